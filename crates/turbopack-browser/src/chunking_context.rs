@@ -67,6 +67,11 @@ impl BrowserChunkingContextBuilder {
         self
     }
 
+    pub fn global_name(mut self, global_name: String) -> Self {
+        self.chunking_context.global_name = global_name;
+        self
+    }
+
     pub fn manifest_chunks(mut self, manifest_chunks: bool) -> Self {
         self.chunking_context.manifest_chunks = manifest_chunks;
         self
@@ -118,6 +123,9 @@ pub struct BrowserChunkingContext {
     environment: Vc<Environment>,
     /// The kind of runtime to include in the output.
     runtime_type: RuntimeType,
+    /// The name of the global chunks are pushed to and the runtime registers
+    /// them from.
+    global_name: String,
     /// Whether to minify resulting chunks
     minify_type: MinifyType,
     /// Whether to use manifest chunks for lazy compilation
@@ -149,6 +157,7 @@ impl BrowserChunkingContext {
                 enable_hot_module_replacement: false,
                 environment,
                 runtime_type,
+                global_name: "TURBOPACK".to_string(),
                 minify_type: MinifyType::NoMinify,
                 manifest_chunks: false,
             },
@@ -170,6 +179,11 @@ impl BrowserChunkingContext {
         self.chunk_base_path
     }
 
+    /// Returns the name of the global chunks are pushed to.
+    pub fn global_name(&self) -> &str {
+        &self.global_name
+    }
+
     /// Returns the minify type.
     pub fn minify_type(&self) -> MinifyType {
         self.minify_type
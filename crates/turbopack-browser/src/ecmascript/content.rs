@@ -85,7 +85,7 @@ impl EcmascriptDevChunkContent {
 
         // When a chunk is executed, it will either register itself with the current
         // instance of the runtime, or it will push itself onto the list of pending
-        // chunks (`self.TURBOPACK`).
+        // chunks (`self.TURBOPACK`, or the configured global name).
         //
         // When the runtime executes (see the `evaluate` module), it will pick up and
         // register all pending chunks, and replace the list of pending chunks
@@ -93,8 +93,9 @@ impl EcmascriptDevChunkContent {
         writedoc!(
             code,
             r#"
-                (globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([{chunk_path}, {{
+                (globalThis[{global_name}] = globalThis[{global_name}] || []).push([{chunk_path}, {{
             "#,
+            global_name = StringifyJs(this.chunking_context.await?.global_name()),
             chunk_path = StringifyJs(chunk_server_path)
         )?;
 
@@ -22,7 +22,7 @@ use turbopack_ecmascript::{
     minify::minify,
     utils::StringifyJs,
 };
-use turbopack_ecmascript_runtime::RuntimeType;
+use turbopack_ecmascript_runtime::{BrowserRuntimeOptions, RuntimeType};
 
 use crate::BrowserChunkingContext;
 
@@ -126,13 +126,13 @@ impl EcmascriptDevEvaluateChunk {
 
         let mut code = CodeBuilder::default();
 
-        // We still use the `TURBOPACK` global variable to store the chunk here,
-        // as there may be another runtime already loaded in the page.
-        // This is the case in integration tests.
+        // We still use the global variable to store the chunk here, as there may
+        // be another runtime already loaded in the page. This is the case in
+        // integration tests.
         writedoc!(
             code,
             r#"
-                (globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+                (globalThis[{global_name}] = globalThis[{global_name}] || []).push([
                     {},
                     {{}},
                     {}
@@ -140,22 +140,30 @@ impl EcmascriptDevEvaluateChunk {
             "#,
             StringifyJs(&chunk_public_path),
             StringifyJs(&params),
+            global_name = StringifyJs(chunking_context.global_name()),
         )?;
 
+        let runtime_options = BrowserRuntimeOptions {
+            chunk_base_path: chunking_context.chunk_base_path().await?.clone_value(),
+            global_name: chunking_context.global_name().to_string(),
+            ..Default::default()
+        }
+        .cell();
+
         match chunking_context.runtime_type() {
             RuntimeType::Development => {
                 let runtime_code = turbopack_ecmascript_runtime::get_browser_runtime_code(
                     environment,
-                    chunking_context.chunk_base_path(),
                     Vc::cell(output_root.to_string()),
+                    runtime_options,
                 );
                 code.push_code(&*runtime_code.await?);
             }
             RuntimeType::Production => {
-                let runtime_code = turbopack_ecmascript_runtime::get_browser_runtime_code(
+                let runtime_code = turbopack_ecmascript_runtime::get_prod_runtime_code(
                     environment,
-                    chunking_context.chunk_base_path(),
                     Vc::cell(output_root.to_string()),
+                    runtime_options,
                 );
                 code.push_code(&*runtime_code.await?);
             }
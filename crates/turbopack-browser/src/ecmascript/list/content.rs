@@ -27,6 +27,7 @@ use super::{
 #[turbo_tasks::value]
 pub(super) struct EcmascriptDevChunkListContent {
     chunk_list_path: String,
+    global_name: String,
     pub(super) chunks_contents: IndexMap<String, Vc<Box<dyn VersionedContent>>>,
     source: EcmascriptDevChunkListSource,
 }
@@ -43,6 +44,11 @@ impl EcmascriptDevChunkListContent {
                 .get_path_to(&*chunk_list.ident().path().await?)
                 .context("chunk list path not in output root")?
                 .to_string(),
+            global_name: chunk_list_ref
+                .chunking_context
+                .await?
+                .global_name()
+                .to_string(),
             chunks_contents: chunk_list_ref
                 .chunks
                 .await?
@@ -124,12 +130,12 @@ impl EcmascriptDevChunkListContent {
         let mut code = CodeBuilder::default();
 
         // When loaded, JS chunks must register themselves with the `TURBOPACK` global
-        // variable. Similarly, we register the chunk list with the
-        // `TURBOPACK_CHUNK_LISTS` global variable.
+        // variable, or the configured global name. Similarly, we register the chunk
+        // list with the `TURBOPACK_CHUNK_LISTS` global variable.
         writedoc!(
             code,
             r#"
-                (globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+                (globalThis[{global_name}] = globalThis[{global_name}] || []).push([
                     {},
                     {{}},
                 ]);
@@ -137,6 +143,7 @@ impl EcmascriptDevChunkListContent {
             "#,
             StringifyJs(&this.chunk_list_path),
             StringifyJs(&params),
+            global_name = StringifyJs(&this.global_name),
         )?;
 
         Ok(Code::cell(code.build()))
@@ -36,6 +36,13 @@ pub enum ChunkLoading {
     NodeJs,
     /// <script> and <link> tags in the browser
     Dom,
+    /// `importScripts` (falling back to `fetch`) in edge workers that opt
+    /// into loading chunks
+    Edge,
+    /// Dynamic `import()` of `file://` URLs in Deno
+    Deno,
+    /// Native dynamic `import()` in Bun
+    Bun,
 }
 
 #[turbo_tasks::value]
@@ -61,6 +68,8 @@ pub enum ExecutionEnvironment {
     NodeJsLambda(Vc<NodeJsEnvironment>),
     EdgeWorker(Vc<EdgeWorkerEnvironment>),
     Browser(Vc<BrowserEnvironment>),
+    Deno(Vc<DenoEnvironment>),
+    Bun(Vc<BunEnvironment>),
     // TODO allow custom trait here
     Custom(u8),
 }
@@ -75,6 +84,8 @@ impl Environment {
             | ExecutionEnvironment::NodeJsLambda(node_env) => node_env.await?.compile_target,
             ExecutionEnvironment::Browser(_) => CompileTarget::unknown(),
             ExecutionEnvironment::EdgeWorker(_) => CompileTarget::unknown(),
+            ExecutionEnvironment::Deno(_) => CompileTarget::unknown(),
+            ExecutionEnvironment::Bun(_) => CompileTarget::current(),
             ExecutionEnvironment::Custom(_) => todo!(),
         })
     }
@@ -91,7 +102,9 @@ impl Environment {
                     &browserslist::Opts::new(),
                 )?)?)
             }
-            ExecutionEnvironment::EdgeWorker(_) => todo!(),
+            ExecutionEnvironment::EdgeWorker(_)
+            | ExecutionEnvironment::Deno(_)
+            | ExecutionEnvironment::Bun(_) => Vc::cell(Versions::default()),
             ExecutionEnvironment::Custom(_) => todo!(),
         })
     }
@@ -100,11 +113,12 @@ impl Environment {
     pub async fn node_externals(self: Vc<Self>) -> Result<Vc<bool>> {
         let this = self.await?;
         Ok(match this.execution {
-            ExecutionEnvironment::NodeJsBuildTime(..) | ExecutionEnvironment::NodeJsLambda(_) => {
-                Vc::cell(true)
-            }
+            ExecutionEnvironment::NodeJsBuildTime(..)
+            | ExecutionEnvironment::NodeJsLambda(_)
+            | ExecutionEnvironment::Bun(_) => Vc::cell(true),
             ExecutionEnvironment::Browser(_) => Vc::cell(false),
             ExecutionEnvironment::EdgeWorker(_) => Vc::cell(false),
+            ExecutionEnvironment::Deno(_) => Vc::cell(false),
             ExecutionEnvironment::Custom(_) => todo!(),
         })
     }
@@ -113,11 +127,12 @@ impl Environment {
     pub async fn supports_esm_externals(self: Vc<Self>) -> Result<Vc<bool>> {
         let this = self.await?;
         Ok(match this.execution {
-            ExecutionEnvironment::NodeJsBuildTime(..) | ExecutionEnvironment::NodeJsLambda(_) => {
-                Vc::cell(true)
-            }
+            ExecutionEnvironment::NodeJsBuildTime(..)
+            | ExecutionEnvironment::NodeJsLambda(_)
+            | ExecutionEnvironment::Bun(_) => Vc::cell(true),
             ExecutionEnvironment::Browser(_) => Vc::cell(false),
             ExecutionEnvironment::EdgeWorker(_) => Vc::cell(false),
+            ExecutionEnvironment::Deno(_) => Vc::cell(true),
             ExecutionEnvironment::Custom(_) => todo!(),
         })
     }
@@ -126,11 +141,12 @@ impl Environment {
     pub async fn supports_commonjs_externals(self: Vc<Self>) -> Result<Vc<bool>> {
         let this = self.await?;
         Ok(match this.execution {
-            ExecutionEnvironment::NodeJsBuildTime(..) | ExecutionEnvironment::NodeJsLambda(_) => {
-                Vc::cell(true)
-            }
+            ExecutionEnvironment::NodeJsBuildTime(..)
+            | ExecutionEnvironment::NodeJsLambda(_)
+            | ExecutionEnvironment::Bun(_) => Vc::cell(true),
             ExecutionEnvironment::Browser(_) => Vc::cell(false),
             ExecutionEnvironment::EdgeWorker(_) => Vc::cell(true),
+            ExecutionEnvironment::Deno(_) => Vc::cell(false),
             ExecutionEnvironment::Custom(_) => todo!(),
         })
     }
@@ -139,11 +155,12 @@ impl Environment {
     pub async fn supports_wasm(self: Vc<Self>) -> Result<Vc<bool>> {
         let this = self.await?;
         Ok(match this.execution {
-            ExecutionEnvironment::NodeJsBuildTime(..) | ExecutionEnvironment::NodeJsLambda(_) => {
-                Vc::cell(true)
-            }
+            ExecutionEnvironment::NodeJsBuildTime(..)
+            | ExecutionEnvironment::NodeJsLambda(_)
+            | ExecutionEnvironment::Bun(_) => Vc::cell(true),
             ExecutionEnvironment::Browser(_) => Vc::cell(false),
             ExecutionEnvironment::EdgeWorker(_) => Vc::cell(false),
+            ExecutionEnvironment::Deno(_) => Vc::cell(false),
             ExecutionEnvironment::Custom(_) => todo!(),
         })
     }
@@ -152,16 +169,16 @@ impl Environment {
     pub async fn resolve_extensions(self: Vc<Self>) -> Result<Vc<Vec<String>>> {
         let env = self.await?;
         Ok(match env.execution {
-            ExecutionEnvironment::NodeJsBuildTime(..) | ExecutionEnvironment::NodeJsLambda(_) => {
-                Vc::cell(vec![
-                    ".js".to_string(),
-                    ".node".to_string(),
-                    ".json".to_string(),
-                ])
-            }
-            ExecutionEnvironment::EdgeWorker(_) | ExecutionEnvironment::Browser(_) => {
-                Vc::<Vec<String>>::default()
-            }
+            ExecutionEnvironment::NodeJsBuildTime(..)
+            | ExecutionEnvironment::NodeJsLambda(_)
+            | ExecutionEnvironment::Bun(_) => Vc::cell(vec![
+                ".js".to_string(),
+                ".node".to_string(),
+                ".json".to_string(),
+            ]),
+            ExecutionEnvironment::EdgeWorker(_)
+            | ExecutionEnvironment::Browser(_)
+            | ExecutionEnvironment::Deno(_) => Vc::<Vec<String>>::default(),
             ExecutionEnvironment::Custom(_) => todo!(),
         })
     }
@@ -170,12 +187,12 @@ impl Environment {
     pub async fn resolve_node_modules(self: Vc<Self>) -> Result<Vc<bool>> {
         let env = self.await?;
         Ok(match env.execution {
-            ExecutionEnvironment::NodeJsBuildTime(..) | ExecutionEnvironment::NodeJsLambda(_) => {
-                Vc::cell(true)
-            }
-            ExecutionEnvironment::EdgeWorker(_) | ExecutionEnvironment::Browser(_) => {
-                Vc::cell(false)
-            }
+            ExecutionEnvironment::NodeJsBuildTime(..)
+            | ExecutionEnvironment::NodeJsLambda(_)
+            | ExecutionEnvironment::Bun(_) => Vc::cell(true),
+            ExecutionEnvironment::EdgeWorker(_)
+            | ExecutionEnvironment::Browser(_)
+            | ExecutionEnvironment::Deno(_) => Vc::cell(false),
             ExecutionEnvironment::Custom(_) => todo!(),
         })
     }
@@ -191,6 +208,8 @@ impl Environment {
             ExecutionEnvironment::EdgeWorker(_) => {
                 Vc::cell(vec!["edge-light".to_string(), "worker".to_string()])
             }
+            ExecutionEnvironment::Bun(_) => Vc::cell(vec!["bun".to_string(), "node".to_string()]),
+            ExecutionEnvironment::Deno(_) => Vc::cell(vec!["deno".to_string()]),
             ExecutionEnvironment::Custom(_) => todo!(),
         })
     }
@@ -212,7 +231,9 @@ impl Environment {
             ExecutionEnvironment::NodeJsBuildTime(_) | ExecutionEnvironment::NodeJsLambda(_) => {
                 Rendering::Server.cell()
             }
-            ExecutionEnvironment::EdgeWorker(_) => Rendering::Server.cell(),
+            ExecutionEnvironment::EdgeWorker(_)
+            | ExecutionEnvironment::Deno(_)
+            | ExecutionEnvironment::Bun(_) => Rendering::Server.cell(),
             ExecutionEnvironment::Browser(_) => Rendering::Client.cell(),
             _ => Rendering::None.cell(),
         })
@@ -225,13 +246,95 @@ impl Environment {
             ExecutionEnvironment::NodeJsBuildTime(_) | ExecutionEnvironment::NodeJsLambda(_) => {
                 ChunkLoading::NodeJs.cell()
             }
-            ExecutionEnvironment::EdgeWorker(_) => ChunkLoading::None.cell(),
+            ExecutionEnvironment::EdgeWorker(edge_worker) => {
+                if edge_worker.await?.load_chunks {
+                    ChunkLoading::Edge.cell()
+                } else {
+                    ChunkLoading::None.cell()
+                }
+            }
             ExecutionEnvironment::Browser(_) => ChunkLoading::Dom.cell(),
+            ExecutionEnvironment::Deno(_) => ChunkLoading::Deno.cell(),
+            ExecutionEnvironment::Bun(_) => ChunkLoading::Bun.cell(),
             _ => ChunkLoading::None.cell(),
         })
     }
+
+    /// Whether every targeted runtime provides `globalThis`
+    #[turbo_tasks::function]
+    pub async fn supports_global_this(self: Vc<Self>) -> Result<Vc<bool>> {
+        let versions = self.runtime_versions().await?;
+        Ok(Vc::cell(GLOBAL_THIS.is_supported_by(&versions)))
+    }
+
+    /// Whether every targeted runtime provides `Promise.allSettled`
+    #[turbo_tasks::function]
+    pub async fn supports_promise_all_settled(self: Vc<Self>) -> Result<Vc<bool>> {
+        let versions = self.runtime_versions().await?;
+        Ok(Vc::cell(PROMISE_ALL_SETTLED.is_supported_by(&versions)))
+    }
+}
+
+/// The first `(major, minor)` version of each runtime that ships a feature.
+/// Internet Explorer never does.
+struct FeatureSupport {
+    android: (u32, u32),
+    chrome: (u32, u32),
+    edge: (u32, u32),
+    firefox: (u32, u32),
+    ios: (u32, u32),
+    node: (u32, u32),
+    opera: (u32, u32),
+    safari: (u32, u32),
+    samsung: (u32, u32),
 }
 
+impl FeatureSupport {
+    /// Runtimes that aren't targeted don't restrict support
+    fn is_supported_by(&self, versions: &Versions) -> bool {
+        fn at_least(version: Option<Version>, (major, minor): (u32, u32)) -> bool {
+            version.map_or(true, |version| {
+                (version.major, version.minor) >= (major, minor)
+            })
+        }
+
+        versions.ie.is_none()
+            && at_least(versions.android, self.android)
+            && at_least(versions.chrome, self.chrome)
+            && at_least(versions.edge, self.edge)
+            && at_least(versions.firefox, self.firefox)
+            && at_least(versions.ios, self.ios)
+            && at_least(versions.node, self.node)
+            && at_least(versions.opera, self.opera)
+            && at_least(versions.safari, self.safari)
+            && at_least(versions.samsung, self.samsung)
+    }
+}
+
+const GLOBAL_THIS: FeatureSupport = FeatureSupport {
+    android: (71, 0),
+    chrome: (71, 0),
+    edge: (79, 0),
+    firefox: (65, 0),
+    ios: (12, 2),
+    node: (12, 0),
+    opera: (58, 0),
+    safari: (12, 1),
+    samsung: (10, 0),
+};
+
+const PROMISE_ALL_SETTLED: FeatureSupport = FeatureSupport {
+    android: (76, 0),
+    chrome: (76, 0),
+    edge: (79, 0),
+    firefox: (71, 0),
+    ios: (13, 0),
+    node: (12, 9),
+    opera: (63, 0),
+    safari: (13, 0),
+    samsung: (12, 0),
+};
+
 pub enum NodeEnvironmentType {
     Server,
 }
@@ -303,7 +406,17 @@ pub struct BrowserEnvironment {
 }
 
 #[turbo_tasks::value(shared)]
-pub struct EdgeWorkerEnvironment {}
+pub struct EdgeWorkerEnvironment {
+    /// Load chunks at runtime with `importScripts` or `fetch`. Otherwise all
+    /// async imports are inlined, as edge workers can't load chunks by default.
+    pub load_chunks: bool,
+}
+
+#[turbo_tasks::value(shared)]
+pub struct DenoEnvironment {}
+
+#[turbo_tasks::value(shared)]
+pub struct BunEnvironment {}
 
 #[turbo_tasks::value(transparent)]
 pub struct RuntimeVersions(#[turbo_tasks(trace_ignore)] pub Versions);
@@ -328,3 +441,66 @@ pub async fn get_current_nodejs_version(env: Vc<Box<dyn ProcessEnv>>) -> Result<
             .to_owned(),
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use swc_core::ecma::preset_env::{Version, Versions};
+
+    use super::{FeatureSupport, GLOBAL_THIS, PROMISE_ALL_SETTLED};
+
+    fn version(version: &str) -> Option<Version> {
+        Some(Version::from_str(version).unwrap())
+    }
+
+    fn supports(feature: &FeatureSupport, versions: Versions) -> bool {
+        feature.is_supported_by(&versions)
+    }
+
+    #[test]
+    fn test_global_this_support() {
+        let chrome = |v| Versions {
+            chrome: version(v),
+            ..Default::default()
+        };
+        assert!(!supports(&GLOBAL_THIS, chrome("70.0.0")));
+        assert!(supports(&GLOBAL_THIS, chrome("71.0.0")));
+
+        let safari = |v| Versions {
+            safari: version(v),
+            ..Default::default()
+        };
+        assert!(!supports(&GLOBAL_THIS, safari("12.0.0")));
+        assert!(supports(&GLOBAL_THIS, safari("12.1.0")));
+    }
+
+    #[test]
+    fn test_promise_all_settled_support() {
+        let node = |v| Versions {
+            node: version(v),
+            ..Default::default()
+        };
+        assert!(!supports(&PROMISE_ALL_SETTLED, node("12.8.0")));
+        assert!(supports(&PROMISE_ALL_SETTLED, node("12.9.0")));
+
+        let mixed = Versions {
+            chrome: version("102.0.0"),
+            firefox: version("70.0.0"),
+            ..Default::default()
+        };
+        assert!(!supports(&PROMISE_ALL_SETTLED, mixed));
+    }
+
+    #[test]
+    fn test_feature_support_without_targets() {
+        assert!(supports(&GLOBAL_THIS, Versions::default()));
+        assert!(!supports(
+            &GLOBAL_THIS,
+            Versions {
+                ie: version("11.0.0"),
+                ..Default::default()
+            }
+        ));
+    }
+}
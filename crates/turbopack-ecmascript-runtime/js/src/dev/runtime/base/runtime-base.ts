@@ -20,6 +20,7 @@ type RefreshRuntimeGlobals =
   import("@next/react-refresh-utils/dist/runtime").RefreshRuntimeGlobals;
 
 declare var CHUNK_BASE_PATH: string;
+declare var PUBLIC_PATH: string;
 declare var $RefreshHelpers$: RefreshRuntimeGlobals["$RefreshHelpers$"];
 declare var $RefreshReg$: RefreshRuntimeGlobals["$RefreshReg$"];
 declare var $RefreshSig$: RefreshRuntimeGlobals["$RefreshSig$"];
@@ -1312,10 +1313,11 @@ function getOrInstantiateRuntimeModule(
 }
 
 /**
- * Returns the URL relative to the origin where a chunk can be fetched from.
+ * Returns the URL relative to the origin where a chunk can be fetched from,
+ * unless the public path points to another origin.
  */
 function getChunkRelativeUrl(chunkPath: ChunkPath): string {
-  return `${CHUNK_BASE_PATH}${chunkPath
+  return `${PUBLIC_PATH}${chunkPath
     .split("/")
     .map((p) => encodeURIComponent(p))
     .join("/")}`;
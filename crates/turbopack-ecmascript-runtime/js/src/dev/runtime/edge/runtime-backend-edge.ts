@@ -0,0 +1,163 @@
+/**
+ * This file contains the runtime code specific to the Turbopack development
+ * ECMAScript Edge runtime (e.g. for web workers), which loads chunks through
+ * `importScripts` when available and falls back to a dynamic `import()`
+ * otherwise.
+ *
+ * It will be appended to the base development runtime code.
+ */
+
+/// <reference path="../base/runtime-base.ts" />
+/// <reference path="../../../shared/require-type.d.ts" />
+
+type ChunkResolver = {
+  resolved: boolean;
+  resolve: () => void;
+  reject: (error?: Error) => void;
+  promise: Promise<void>;
+};
+
+let BACKEND: RuntimeBackend;
+
+function augmentContext(context: TurbopackDevBaseContext): TurbopackDevContext {
+  return context;
+}
+
+function fetchWebAssembly(wasmChunkPath: ChunkPath) {
+  return fetch(getChunkRelativeUrl(wasmChunkPath));
+}
+
+async function loadWebAssembly(
+  _source: SourceInfo,
+  wasmChunkPath: ChunkPath,
+  importsObj: WebAssembly.Imports
+): Promise<Exports> {
+  const req = fetchWebAssembly(wasmChunkPath);
+
+  const { instance } = await WebAssembly.instantiateStreaming(req, importsObj);
+
+  return instance.exports;
+}
+
+async function loadWebAssemblyModule(
+  _source: SourceInfo,
+  wasmChunkPath: ChunkPath
+): Promise<WebAssembly.Module> {
+  const req = fetchWebAssembly(wasmChunkPath);
+
+  return await WebAssembly.compileStreaming(req);
+}
+
+(() => {
+  BACKEND = {
+    async registerChunk(chunkPath, params) {
+      const resolver = getOrCreateResolver(chunkPath);
+      resolver.resolve();
+
+      if (params == null) {
+        return;
+      }
+
+      for (const otherChunkData of params.otherChunks) {
+        const otherChunkPath = getChunkPath(otherChunkData);
+        // Chunk might have started loading, so we want to avoid triggering another load.
+        getOrCreateResolver(otherChunkPath);
+      }
+
+      // This waits for chunks to be loaded, but also marks included items as available.
+      await Promise.all(
+        params.otherChunks.map((otherChunkData) =>
+          loadChunk({ type: SourceType.Runtime, chunkPath }, otherChunkData)
+        )
+      );
+
+      if (params.runtimeModuleIds.length > 0) {
+        for (const moduleId of params.runtimeModuleIds) {
+          getOrInstantiateRuntimeModule(moduleId, chunkPath);
+        }
+      }
+    },
+
+    loadChunk(chunkPath) {
+      return doLoadChunk(chunkPath);
+    },
+
+    restart: () => {
+      throw new Error("restart is not supported");
+    },
+  };
+
+  /**
+   * Maps chunk paths to the corresponding resolver.
+   */
+  const chunkResolvers: Map<ChunkPath, ChunkResolver> = new Map();
+
+  function getOrCreateResolver(chunkPath: ChunkPath): ChunkResolver {
+    let resolver = chunkResolvers.get(chunkPath);
+    if (!resolver) {
+      let resolve: () => void;
+      let reject: (error?: Error) => void;
+      const promise = new Promise<void>((innerResolve, innerReject) => {
+        resolve = innerResolve;
+        reject = innerReject;
+      });
+      resolver = {
+        resolved: false,
+        promise,
+        resolve: () => {
+          resolver!.resolved = true;
+          resolve();
+        },
+        reject: reject!,
+      };
+      chunkResolvers.set(chunkPath, resolver);
+    }
+    return resolver;
+  }
+
+  /**
+   * Loads the given chunk, and returns a promise that resolves once the chunk
+   * has been loaded.
+   */
+  async function doLoadChunk(chunkPath: ChunkPath) {
+    const resolver = getOrCreateResolver(chunkPath);
+    if (resolver.resolved) {
+      return resolver.promise;
+    }
+
+    if (!chunkPath.endsWith(".js")) {
+      // There is no DOM to apply CSS or other non-JS chunks to, so they are
+      // marked as loaded instantly.
+      resolver.resolve();
+      return resolver.promise;
+    }
+
+    // Nothing else loads the chunks referenced from runtime code in a worker,
+    // so every chunk is loaded here. It resolves its resolver once it
+    // registers itself through `registerChunk`.
+    const chunkUrl = getChunkRelativeUrl(chunkPath);
+
+    try {
+      if (typeof importScripts === "function") {
+        // `importScripts` evaluates the chunk synchronously.
+        importScripts(chunkUrl);
+      } else {
+        // Edge runtimes forbid `eval`, so the chunk is imported instead.
+        await import(chunkUrl);
+      }
+    } catch (error) {
+      resolver.reject(error as Error);
+    }
+
+    return resolver.promise;
+  }
+})();
+
+function _eval({ code, url, map }: EcmascriptModuleEntry): ModuleFactory {
+  code += `\n\n//# sourceURL=${encodeURI(CHUNK_BASE_PATH + url)}`;
+  if (map)
+    code += `\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,${btoa(
+      map
+    )}`;
+  return eval(code);
+}
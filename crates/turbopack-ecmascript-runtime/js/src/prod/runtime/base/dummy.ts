@@ -0,0 +1,26 @@
+/**
+ * This file acts as a dummy implementor for the interface that
+ * `runtime-base.ts` expects to be available in the global scope.
+ *
+ * This interface will be implemented by runtime backends.
+ */
+
+/// <reference path="../../../shared/require-type.d.ts" />
+
+declare var BACKEND: RuntimeBackend;
+/**
+ * Adds additional properties to the `TurbopackProdBaseContext` interface.
+ */
+declare var augmentContext: (
+  context: TurbopackProdBaseContext
+) => TurbopackProdContext;
+declare var loadWebAssembly: (
+  source: SourceInfo,
+  wasmChunkPath: ChunkPath,
+  imports: WebAssembly.Imports
+) => Exports;
+declare var loadWebAssemblyModule: (
+  source: SourceInfo,
+  wasmChunkPath: ChunkPath
+) => WebAssembly.Module;
+declare var relativeURL: (inputUrl: string) => void;
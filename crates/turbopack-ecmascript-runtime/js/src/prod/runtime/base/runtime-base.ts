@@ -0,0 +1,369 @@
+/**
+ * This file contains runtime types and functions that are shared between all
+ * Turbopack *production* ECMAScript runtimes.
+ *
+ * It will be appended to the runtime code of each runtime right after the
+ * shared runtime utils.
+ *
+ * Unlike the development runtime base, it does not keep track of the
+ * information required for HMR (hot state, chunk lists, module parents across
+ * updates), nor does it subscribe to chunk list updates.
+ */
+
+/* eslint-disable @next/next/no-assign-module-variable */
+
+/// <reference path="../../../shared/runtime-utils.ts" />
+/// <reference path="./globals.d.ts" />
+
+declare var CHUNK_BASE_PATH: string;
+declare var PUBLIC_PATH: string;
+
+interface TurbopackProdBaseContext extends TurbopackBaseContext {
+  R: ResolvePathFromModule;
+}
+
+interface TurbopackProdContext extends TurbopackProdBaseContext {}
+
+type ModuleFactory = (
+  this: Module["exports"],
+  context: TurbopackProdContext
+) => undefined;
+
+type ProdRuntimeParams = {
+  otherChunks: ChunkData[];
+  runtimeModuleIds: ModuleId[];
+};
+
+type ChunkRegistration = [
+  chunkPath: ChunkPath,
+  chunkModules: ModuleFactories,
+  params: ProdRuntimeParams | undefined
+];
+
+enum SourceType {
+  /**
+   * The module was instantiated because it was included in an evaluated chunk's
+   * runtime.
+   */
+  Runtime = 0,
+  /**
+   * The module was instantiated because a parent module imported it.
+   */
+  Parent = 1,
+}
+
+type SourceInfo =
+  | {
+      type: SourceType.Runtime;
+      chunkPath: ChunkPath;
+    }
+  | {
+      type: SourceType.Parent;
+      parentId: ModuleId;
+    };
+
+interface RuntimeBackend {
+  registerChunk: (chunkPath: ChunkPath, params?: ProdRuntimeParams) => void;
+  loadChunk: (chunkPath: ChunkPath, source: SourceInfo) => Promise<void>;
+}
+
+const moduleFactories: ModuleFactories = Object.create(null);
+const moduleCache: ModuleCache = Object.create(null);
+/**
+ * Map from module ID to the chunks that contain this module.
+ */
+const moduleChunksMap: Map<ModuleId, Set<ChunkPath>> = new Map();
+
+const availableModules: Map<ModuleId, Promise<any> | true> = new Map();
+
+const availableModuleChunks: Map<ChunkPath, Promise<any> | true> = new Map();
+
+async function loadChunk(
+  source: SourceInfo,
+  chunkData: ChunkData
+): Promise<any> {
+  if (typeof chunkData === "string") {
+    return loadChunkPath(source, chunkData);
+  }
+
+  const includedList = chunkData.included || [];
+  const modulesPromises = includedList.map((included) => {
+    if (moduleFactories[included]) return true;
+    return availableModules.get(included);
+  });
+  if (modulesPromises.length > 0 && modulesPromises.every((p) => p)) {
+    // When all included items are already loaded or loading, we can skip loading ourselves
+    return Promise.all(modulesPromises);
+  }
+
+  const includedModuleChunksList = chunkData.moduleChunks || [];
+  const moduleChunksPromises = includedModuleChunksList
+    .map((included) => availableModuleChunks.get(included))
+    .filter((p) => p);
+
+  let promise;
+  if (moduleChunksPromises.length > 0) {
+    // Some module chunks are already loaded or loading.
+
+    if (moduleChunksPromises.length == includedModuleChunksList.length) {
+      // When all included module chunks are already loaded or loading, we can skip loading ourselves
+      return Promise.all(moduleChunksPromises);
+    }
+
+    const moduleChunksToLoad: Set<ChunkPath> = new Set();
+    for (const moduleChunk of includedModuleChunksList) {
+      if (!availableModuleChunks.has(moduleChunk)) {
+        moduleChunksToLoad.add(moduleChunk);
+      }
+    }
+
+    for (const moduleChunkToLoad of moduleChunksToLoad) {
+      const promise = loadChunkPath(source, moduleChunkToLoad);
+
+      availableModuleChunks.set(moduleChunkToLoad, promise);
+
+      moduleChunksPromises.push(promise);
+    }
+
+    promise = Promise.all(moduleChunksPromises);
+  } else {
+    promise = loadChunkPath(source, chunkData.path);
+
+    // Mark all included module chunks as loading if they are not already loaded or loading.
+    for (const includedModuleChunk of includedModuleChunksList) {
+      if (!availableModuleChunks.has(includedModuleChunk)) {
+        availableModuleChunks.set(includedModuleChunk, promise);
+      }
+    }
+  }
+
+  for (const included of includedList) {
+    if (!availableModules.has(included)) {
+      availableModules.set(included, promise);
+    }
+  }
+
+  return promise;
+}
+
+async function loadChunkPath(
+  source: SourceInfo,
+  chunkPath: ChunkPath
+): Promise<any> {
+  try {
+    await BACKEND.loadChunk(chunkPath, source);
+  } catch (error) {
+    let loadReason;
+    switch (source.type) {
+      case SourceType.Runtime:
+        loadReason = `as a runtime dependency of chunk ${source.chunkPath}`;
+        break;
+      case SourceType.Parent:
+        loadReason = `from module ${source.parentId}`;
+        break;
+    }
+    throw new Error(
+      `Failed to load chunk ${chunkPath} ${loadReason}${
+        error ? `: ${error}` : ""
+      }`,
+      error
+        ? {
+            cause: error,
+          }
+        : undefined
+    );
+  }
+}
+
+/**
+ * Returns an absolute url to an asset.
+ */
+function createResolvePathFromModule(
+  resolver: (moduleId: string) => Exports
+): (moduleId: string) => string {
+  return function resolvePathFromModule(moduleId: string): string {
+    const exported = resolver(moduleId);
+    return exported?.default ?? exported;
+  };
+}
+
+function instantiateModule(id: ModuleId, source: SourceInfo): Module {
+  const moduleFactory = moduleFactories[id];
+  if (typeof moduleFactory !== "function") {
+    let instantiationReason;
+    switch (source.type) {
+      case SourceType.Runtime:
+        instantiationReason = `as a runtime entry of chunk ${source.chunkPath}`;
+        break;
+      case SourceType.Parent:
+        instantiationReason = `because it was required from module ${source.parentId}`;
+        break;
+    }
+    throw new Error(
+      `Module ${id} was instantiated ${instantiationReason}, but the module factory is not available.`
+    );
+  }
+
+  let parents: ModuleId[];
+  switch (source.type) {
+    case SourceType.Runtime:
+      parents = [];
+      break;
+    case SourceType.Parent:
+      // No need to add this module as a child of the parent module here, this
+      // has already been taken care of in `getOrInstantiateModuleFromParent`.
+      parents = [source.parentId];
+      break;
+  }
+  const module: Module = {
+    exports: {},
+    error: undefined,
+    loaded: false,
+    id,
+    parents,
+    children: [],
+    namespaceObject: undefined,
+  };
+
+  moduleCache[id] = module;
+
+  // NOTE(alexkirsz) This can fail when the module encounters a runtime error.
+  try {
+    const sourceInfo: SourceInfo = { type: SourceType.Parent, parentId: id };
+    const r = commonJsRequire.bind(null, module);
+    moduleFactory.call(
+      module.exports,
+      augmentContext({
+        a: asyncModule.bind(null, module),
+        e: module.exports,
+        r,
+        t: runtimeRequire,
+        f: moduleContext,
+        i: esmImport.bind(null, module),
+        s: esmExport.bind(null, module, module.exports),
+        j: dynamicExport.bind(null, module, module.exports),
+        v: exportValue.bind(null, module),
+        n: exportNamespace.bind(null, module),
+        m: module,
+        c: moduleCache,
+        M: moduleFactories,
+        l: loadChunk.bind(null, sourceInfo),
+        w: loadWebAssembly.bind(null, sourceInfo),
+        u: loadWebAssemblyModule.bind(null, sourceInfo),
+        g: globalThis,
+        U: relativeURL,
+        R: createResolvePathFromModule(r),
+        __dirname: module.id.replace(/(^|\/)\/+$/, ""),
+      })
+    );
+  } catch (error) {
+    module.error = error as any;
+    throw error;
+  }
+
+  module.loaded = true;
+  if (module.namespaceObject && module.exports !== module.namespaceObject) {
+    // in case of a circular dependency: cjs1 -> esm2 -> cjs1
+    interopEsm(module.exports, module.namespaceObject);
+  }
+
+  return module;
+}
+
+/**
+ * Retrieves a module from the cache, or instantiate it if it is not cached.
+ */
+const getOrInstantiateModuleFromParent: GetOrInstantiateModuleFromParent = (
+  id,
+  sourceModule
+) => {
+  const module = moduleCache[id];
+
+  if (sourceModule.children.indexOf(id) === -1) {
+    sourceModule.children.push(id);
+  }
+
+  if (module) {
+    if (module.parents.indexOf(sourceModule.id) === -1) {
+      module.parents.push(sourceModule.id);
+    }
+
+    return module;
+  }
+
+  return instantiateModule(id, {
+    type: SourceType.Parent,
+    parentId: sourceModule.id,
+  });
+};
+
+/**
+ * Adds a module to a chunk.
+ */
+function addModuleToChunk(moduleId: ModuleId, chunkPath: ChunkPath) {
+  let moduleChunks = moduleChunksMap.get(moduleId);
+  if (!moduleChunks) {
+    moduleChunks = new Set([chunkPath]);
+    moduleChunksMap.set(moduleId, moduleChunks);
+  } else {
+    moduleChunks.add(chunkPath);
+  }
+}
+
+/**
+ * Returns the first chunk that included a module.
+ * This is used by the Node.js backend, hence why it's marked as unused in this
+ * file.
+ */
+function getFirstModuleChunk(moduleId: ModuleId) {
+  const moduleChunkPaths = moduleChunksMap.get(moduleId);
+  if (moduleChunkPaths == null) {
+    return null;
+  }
+
+  return moduleChunkPaths.values().next().value;
+}
+
+/**
+ * Gets or instantiates a runtime module.
+ */
+function getOrInstantiateRuntimeModule(
+  moduleId: ModuleId,
+  chunkPath: ChunkPath
+): Module {
+  const module = moduleCache[moduleId];
+  if (module) {
+    if (module.error) {
+      throw module.error;
+    }
+    return module;
+  }
+
+  return instantiateModule(moduleId, { type: SourceType.Runtime, chunkPath });
+}
+
+/**
+ * Returns the URL relative to the origin where a chunk can be fetched from,
+ * unless the public path points to another origin.
+ */
+function getChunkRelativeUrl(chunkPath: ChunkPath): string {
+  return `${PUBLIC_PATH}${chunkPath
+    .split("/")
+    .map((p) => encodeURIComponent(p))
+    .join("/")}`;
+}
+
+function registerChunk([
+  chunkPath,
+  chunkModules,
+  runtimeParams,
+]: ChunkRegistration) {
+  for (const [moduleId, moduleFactory] of Object.entries(chunkModules)) {
+    if (!moduleFactories[moduleId]) {
+      moduleFactories[moduleId] = moduleFactory;
+    }
+    addModuleToChunk(moduleId, chunkPath);
+  }
+
+  return BACKEND.registerChunk(chunkPath, runtimeParams);
+}
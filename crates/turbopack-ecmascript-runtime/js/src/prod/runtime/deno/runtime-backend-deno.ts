@@ -0,0 +1,170 @@
+/**
+ * This file contains the runtime code specific to the Turbopack production
+ * ECMAScript Deno runtime, which loads chunks through dynamic `import()` of
+ * `file://` URLs.
+ *
+ * It will be appended to the base production runtime code.
+ */
+
+/// <reference path="../base/runtime-base.ts" />
+
+type ChunkResolver = {
+  resolved: boolean;
+  resolve: () => void;
+  reject: (error?: Error) => void;
+  promise: Promise<void>;
+};
+
+let BACKEND: RuntimeBackend;
+
+function augmentContext(context: TurbopackProdBaseContext): TurbopackProdContext {
+  return context;
+}
+
+/**
+ * The URL of the output directory, which chunk paths are relative to.
+ */
+let outputRootUrl: URL | undefined;
+
+/**
+ * Derives the URL of the output directory from the URL of the chunk the
+ * runtime is evaluated in, as the main module might live anywhere.
+ */
+function setOutputRootUrl(runtimeChunkPath: ChunkPath) {
+  const depth = runtimeChunkPath.split("/").length - 1;
+  outputRootUrl ??= new URL(`./${"../".repeat(depth)}`, import.meta.url);
+}
+
+/**
+ * Returns the URL of a chunk, resolved against the output directory. A
+ * `/`-prefixed public path is relative to the output directory too, instead
+ * of the root of the file system.
+ */
+function getChunkUrl(chunkPath: ChunkPath): URL {
+  return new URL(
+    getChunkRelativeUrl(chunkPath).replace(/^\/+/, ""),
+    outputRootUrl
+  );
+}
+
+function fetchWebAssembly(wasmChunkPath: ChunkPath) {
+  return fetch(getChunkUrl(wasmChunkPath));
+}
+
+async function loadWebAssembly(
+  _source: SourceInfo,
+  wasmChunkPath: ChunkPath,
+  importsObj: WebAssembly.Imports
+): Promise<Exports> {
+  const req = fetchWebAssembly(wasmChunkPath);
+
+  const { instance } = await WebAssembly.instantiateStreaming(req, importsObj);
+
+  return instance.exports;
+}
+
+async function loadWebAssemblyModule(
+  _source: SourceInfo,
+  wasmChunkPath: ChunkPath
+): Promise<WebAssembly.Module> {
+  const req = fetchWebAssembly(wasmChunkPath);
+
+  return await WebAssembly.compileStreaming(req);
+}
+
+(() => {
+  BACKEND = {
+    async registerChunk(chunkPath, params) {
+      const resolver = getOrCreateResolver(chunkPath);
+      resolver.resolve();
+
+      if (params == null) {
+        return;
+      }
+
+      // Only chunks that evaluate modules come with params, and the first one
+      // of them is the chunk the runtime is part of.
+      setOutputRootUrl(chunkPath);
+
+      for (const otherChunkData of params.otherChunks) {
+        const otherChunkPath = getChunkPath(otherChunkData);
+        // Chunk might have started loading, so we want to avoid triggering another load.
+        getOrCreateResolver(otherChunkPath);
+      }
+
+      // This waits for chunks to be loaded, but also marks included items as available.
+      await Promise.all(
+        params.otherChunks.map((otherChunkData) =>
+          loadChunk({ type: SourceType.Runtime, chunkPath }, otherChunkData)
+        )
+      );
+
+      if (params.runtimeModuleIds.length > 0) {
+        for (const moduleId of params.runtimeModuleIds) {
+          getOrInstantiateRuntimeModule(moduleId, chunkPath);
+        }
+      }
+    },
+
+    loadChunk(chunkPath) {
+      return doLoadChunk(chunkPath);
+    },
+  };
+
+  /**
+   * Maps chunk paths to the corresponding resolver.
+   */
+  const chunkResolvers: Map<ChunkPath, ChunkResolver> = new Map();
+
+  function getOrCreateResolver(chunkPath: ChunkPath): ChunkResolver {
+    let resolver = chunkResolvers.get(chunkPath);
+    if (!resolver) {
+      let resolve: () => void;
+      let reject: (error?: Error) => void;
+      const promise = new Promise<void>((innerResolve, innerReject) => {
+        resolve = innerResolve;
+        reject = innerReject;
+      });
+      resolver = {
+        resolved: false,
+        promise,
+        resolve: () => {
+          resolver!.resolved = true;
+          resolve();
+        },
+        reject: reject!,
+      };
+      chunkResolvers.set(chunkPath, resolver);
+    }
+    return resolver;
+  }
+
+  /**
+   * Loads the given chunk, and returns a promise that resolves once the chunk
+   * has been loaded.
+   */
+  async function doLoadChunk(chunkPath: ChunkPath) {
+    const resolver = getOrCreateResolver(chunkPath);
+    if (resolver.resolved) {
+      return resolver.promise;
+    }
+
+    if (!chunkPath.endsWith(".js")) {
+      // There is no DOM to apply CSS or other non-JS chunks to, so they are
+      // marked as loaded instantly.
+      resolver.resolve();
+      return resolver.promise;
+    }
+
+    // Nothing else loads the chunks referenced from runtime code in Deno, so
+    // every chunk is imported here.
+    try {
+      // Evaluating the chunk registers it through `registerChunk`.
+      await import(getChunkUrl(chunkPath).href);
+    } catch (error) {
+      resolver.reject(error as Error);
+    }
+
+    return resolver.promise;
+  }
+})();
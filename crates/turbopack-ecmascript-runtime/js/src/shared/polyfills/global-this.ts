@@ -0,0 +1,25 @@
+/**
+ * Defines `globalThis` for runtimes that predate it.
+ *
+ * It is prepended to the runtime code when the target environment doesn't
+ * support `globalThis`, since the runtime bootstrap relies on it.
+ */
+
+(function () {
+  if (typeof globalThis === "object") {
+    return;
+  }
+
+  // Getters on `Object.prototype` are called with the global object as
+  // `this` when accessed through an unqualified identifier.
+  Object.defineProperty(Object.prototype, "__turbopack_global__", {
+    get() {
+      return this;
+    },
+    configurable: true,
+  });
+  // @ts-ignore the property is only defined for the duration of this block
+  __turbopack_global__.globalThis = __turbopack_global__;
+  // @ts-ignore
+  delete Object.prototype.__turbopack_global__;
+})();
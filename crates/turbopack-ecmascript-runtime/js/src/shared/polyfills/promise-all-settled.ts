@@ -0,0 +1,19 @@
+/**
+ * Defines `Promise.allSettled` for runtimes that predate it.
+ *
+ * It is prepended to the runtime code when the target environment doesn't
+ * support `Promise.allSettled`.
+ */
+
+if (typeof Promise.allSettled !== "function") {
+  Promise.allSettled = function allSettled(values: Iterable<unknown>) {
+    return Promise.all(
+      Array.from(values, (value) =>
+        Promise.resolve(value).then(
+          (value) => ({ status: "fulfilled", value }),
+          (reason) => ({ status: "rejected", reason })
+        )
+      )
+    );
+  } as typeof Promise.allSettled;
+}
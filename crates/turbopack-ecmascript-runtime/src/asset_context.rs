@@ -9,6 +9,10 @@ use turbopack_core::{
 use turbopack_ecmascript::TreeShakingMode;
 
 /// Returns the runtime asset context to use to process runtime code assets.
+///
+/// This is a task of its own so that every runtime built for an environment
+/// shares one asset context, and with it the embedded runtime files.
+#[turbo_tasks::function]
 pub fn get_runtime_asset_context(environment: Vc<Environment>) -> Vc<Box<dyn AssetContext>> {
     let module_options_context = ModuleOptionsContext {
         enable_typescript_transform: Some(TypescriptTransformOptions::default().cell()),
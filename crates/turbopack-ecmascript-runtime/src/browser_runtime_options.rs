@@ -0,0 +1,65 @@
+use turbo_tasks::{ValueDefault, Vc};
+use turbopack_core::code_builder::Code;
+
+use crate::output_format::RuntimeOutputFormat;
+
+/// Options for the browser ECMAScript runtime, see
+/// [get_browser_runtime_code](crate::get_browser_runtime_code).
+#[turbo_tasks::value(shared)]
+#[derive(Clone, Debug)]
+pub struct BrowserRuntimeOptions {
+    /// Base path that will be prepended to all chunk URLs when loading them.
+    pub chunk_base_path: Option<String>,
+    /// Emitted as `PUBLIC_PATH`, which the runtime loads chunks from, for
+    /// deployments that serve chunks from a different location, like a CDN,
+    /// than `chunk_base_path`. Falls back to the chunk base path.
+    pub public_path: Option<String>,
+    /// The name of the global chunks are pushed to. It is escaped wherever
+    /// it is referenced, so it can be any string.
+    pub global_name: String,
+    /// The runtime does nothing unless the global is already an array.
+    /// Otherwise it initializes the global itself, for environments that only
+    /// set it up after the runtime has loaded.
+    pub strict_bootstrap: bool,
+    /// A chunk that fails to register is reported to
+    /// `globalThis.__TURBOPACK_CHUNK_ERROR_HANDLER__`, or the console if there
+    /// is no such handler, instead of stopping the registration of later
+    /// chunks.
+    pub isolate_chunk_errors: bool,
+    /// Appended after the runtime backend, right before the chunks pushed so
+    /// far are registered.
+    pub extra_runtime_code: Option<Vc<Code>>,
+    /// When false, the shared runtime utils are left out, for environments
+    /// that already provide them globally.
+    pub include_runtime_utils: bool,
+    /// When false, the runtime base and backend are taken from the production
+    /// runtime, which doesn't include any HMR machinery.
+    pub enable_hmr: bool,
+    /// Whether the runtime is wrapped in an IIFE or emitted as an ECMAScript
+    /// module exporting `registerChunk`.
+    pub output_format: RuntimeOutputFormat,
+}
+
+impl Default for BrowserRuntimeOptions {
+    fn default() -> Self {
+        BrowserRuntimeOptions {
+            chunk_base_path: None,
+            public_path: None,
+            global_name: "TURBOPACK".to_string(),
+            strict_bootstrap: true,
+            isolate_chunk_errors: false,
+            extra_runtime_code: None,
+            include_runtime_utils: true,
+            enable_hmr: true,
+            output_format: RuntimeOutputFormat::Iife,
+        }
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ValueDefault for BrowserRuntimeOptions {
+    #[turbo_tasks::function]
+    fn value_default() -> Vc<Self> {
+        Self::default().cell()
+    }
+}
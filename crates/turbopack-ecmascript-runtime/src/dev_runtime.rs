@@ -10,62 +10,93 @@ use turbopack_core::{
 };
 use turbopack_ecmascript::utils::StringifyJs;
 
-use crate::{asset_context::get_runtime_asset_context, embed_js::embed_static_code};
+use crate::{
+    asset_context::get_runtime_asset_context, browser_runtime_options::BrowserRuntimeOptions,
+    embed_js::embed_static_code, output_format::RuntimeOutputFormat,
+};
 
-/// Returns the code for the development ECMAScript runtime.
+/// Returns the code for the development ECMAScript runtime, configured by
+/// `options`.
+///
+/// Every embedded runtime file is pushed together with its own source map, so
+/// calling `generate_source_map` on the returned [Code] yields a sectioned
+/// source map with entries for the runtime utils, base and backend sections.
 #[turbo_tasks::function]
 pub async fn get_browser_runtime_code(
     environment: Vc<Environment>,
-    chunk_base_path: Vc<Option<String>>,
     output_root: Vc<String>,
+    options: Vc<BrowserRuntimeOptions>,
 ) -> Result<Vc<Code>> {
+    let options = options.await?;
     let asset_context = get_runtime_asset_context(environment);
+    let runtime_dir = if options.enable_hmr { "dev" } else { "prod" };
 
     let shared_runtime_utils_code =
         embed_static_code(asset_context, "shared/runtime-utils.ts".to_string());
     let runtime_base_code = embed_static_code(
         asset_context,
-        "dev/runtime/base/runtime-base.ts".to_string(),
+        format!("{runtime_dir}/runtime/base/runtime-base.ts"),
     );
 
-    let chunk_loading = &*asset_context
-        .compile_time_info()
-        .environment()
-        .chunk_loading()
-        .await?;
-
-    let runtime_backend_code = embed_static_code(
-        asset_context,
-        match chunk_loading {
-            ChunkLoading::None => "dev/runtime/none/runtime-backend-none.ts".to_string(),
-            ChunkLoading::NodeJs => "dev/runtime/nodejs/runtime-backend-nodejs.ts".to_string(),
-            ChunkLoading::Dom => "dev/runtime/dom/runtime-backend-dom.ts".to_string(),
-        },
-    );
+    let runtime_backend_code = get_runtime_backend_code(asset_context, options.enable_hmr);
 
     let mut code: CodeBuilder = CodeBuilder::default();
+    code.push_code(&*get_runtime_polyfills_code(environment).await?);
+
     let output_root = output_root.await?.to_string();
-    let chunk_base_path = &*chunk_base_path.await?;
-    let chunk_base_path = chunk_base_path.as_ref().map_or_else(|| "", |f| f.as_str());
+    let chunk_base_path =
+        normalize_chunk_base_path(options.chunk_base_path.as_deref().unwrap_or_default());
+    let public_path = match &options.public_path {
+        Some(public_path) => normalize_chunk_base_path(public_path),
+        None => chunk_base_path.clone(),
+    };
+    let global_name = StringifyJs(&options.global_name);
+    let output_format = options.output_format;
+    let strict_bootstrap = options.strict_bootstrap;
+
+    if output_format == RuntimeOutputFormat::Iife {
+        writeln!(code, "(() => {{")?;
+        if strict_bootstrap {
+            writedoc!(
+                code,
+                r#"
+                    if (!Array.isArray(globalThis[{global_name}])) {{
+                        return;
+                    }}
+
+                "#,
+            )?;
+        }
+    }
+    if !strict_bootstrap {
+        writedoc!(
+            code,
+            r#"
+                globalThis[{global_name}] = globalThis[{global_name}] || [];
+
+            "#,
+        )?;
+    }
 
     writedoc!(
         code,
         r#"
-            (() => {{
-            if (!Array.isArray(globalThis.TURBOPACK)) {{
-                return;
-            }}
-
             const CHUNK_BASE_PATH = {};
             const RUNTIME_PUBLIC_PATH = {};
+            const PUBLIC_PATH = {};
             const OUTPUT_ROOT = {};
+            {}
         "#,
-        StringifyJs(chunk_base_path),
-        StringifyJs(chunk_base_path),
+        StringifyJs(&chunk_base_path),
+        StringifyJs(&chunk_base_path),
+        StringifyJs(&public_path),
         StringifyJs(output_root.as_str()),
+        runtime_version_marker(),
     )?;
 
-    code.push_code(&*shared_runtime_utils_code.await?);
+    if options.include_runtime_utils {
+        code.push_code(&*shared_runtime_utils_code.await?);
+    }
     code.push_code(&*runtime_base_code.await?);
 
     if *environment.supports_commonjs_externals().await? {
@@ -95,17 +126,668 @@ pub async fn get_browser_runtime_code(
 
     code.push_code(&*runtime_backend_code.await?);
 
+    if let Some(extra_runtime_code) = options.extra_runtime_code {
+        code.push_code(&*extra_runtime_code.await?);
+    }
+
+    let register_chunk = if options.isolate_chunk_errors {
+        writedoc!(
+            code,
+            r#"
+                function reportChunkError(error) {{
+                    const handler = globalThis.__TURBOPACK_CHUNK_ERROR_HANDLER__;
+                    if (typeof handler === "function") {{
+                        handler(error);
+                    }} else {{
+                        console.error(error);
+                    }}
+                }}
+                function registerChunkIsolated(chunk) {{
+                    try {{
+                        Promise.resolve(registerChunk(chunk)).catch(reportChunkError);
+                    }} catch (error) {{
+                        reportChunkError(error);
+                    }}
+                }}
+            "#,
+        )?;
+        "registerChunkIsolated"
+    } else {
+        "registerChunk"
+    };
+
     // Registering chunks depends on the BACKEND variable, which is set by the
     // specific runtime code, hence it must be appended after it.
-    writedoc!(
-        code,
-        r#"
-            const chunksToRegister = globalThis.TURBOPACK;
-            globalThis.TURBOPACK = {{ push: registerChunk }};
-            chunksToRegister.forEach(registerChunk);
-            }})();
-        "#
-    )?;
+    match output_format {
+        RuntimeOutputFormat::Iife => writedoc!(
+            code,
+            r#"
+                const chunksToRegister = globalThis[{global_name}];
+                globalThis[{global_name}] = {{ push: {register_chunk} }};
+                chunksToRegister.forEach({register_chunk});
+                }})();
+            "#,
+        )?,
+        // A module can't return early, so the check the IIFE does up front
+        // guards the registration instead.
+        RuntimeOutputFormat::EsModule => writedoc!(
+            code,
+            r#"
+                const chunksToRegister = globalThis[{global_name}];
+                if (Array.isArray(chunksToRegister)) {{
+                    globalThis[{global_name}] = {{ push: {register_chunk} }};
+                    chunksToRegister.forEach({register_chunk});
+                }}
+                export {{ registerChunk }};
+            "#,
+        )?,
+    }
 
     Ok(Code::cell(code.build()))
 }
+
+/// Returns the code for the production ECMAScript runtime, which is the
+/// development runtime configured by `options` without any HMR machinery.
+#[turbo_tasks::function]
+pub async fn get_prod_runtime_code(
+    environment: Vc<Environment>,
+    output_root: Vc<String>,
+    options: Vc<BrowserRuntimeOptions>,
+) -> Result<Vc<Code>> {
+    let options = BrowserRuntimeOptions {
+        enable_hmr: false,
+        ..options.await?.clone_value()
+    };
+    Ok(get_browser_runtime_code(
+        environment,
+        output_root,
+        options.cell(),
+    ))
+}
+
+/// Returns the polyfills for the runtime features the environment lacks. They
+/// are pushed before the runtime bootstrap, which already relies on them.
+#[turbo_tasks::function]
+async fn get_runtime_polyfills_code(environment: Vc<Environment>) -> Result<Vc<Code>> {
+    let asset_context = get_runtime_asset_context(environment);
+    let mut code = CodeBuilder::default();
+
+    if !*environment.supports_global_this().await? {
+        code.push_code(
+            &*embed_static_code(asset_context, "shared/polyfills/global-this.ts".to_string())
+                .await?,
+        );
+    }
+    if !*environment.supports_promise_all_settled().await? {
+        code.push_code(
+            &*embed_static_code(
+                asset_context,
+                "shared/polyfills/promise-all-settled.ts".to_string(),
+            )
+            .await?,
+        );
+    }
+
+    Ok(Code::cell(code.build()))
+}
+
+/// Returns the runtime backend matching the chunk loading of the runtime
+/// asset context's environment. It is kept separate from
+/// [get_browser_runtime_code] so that changes to its other inputs, like the
+/// chunk base path, don't invalidate the backend selection.
+#[turbo_tasks::function]
+async fn get_runtime_backend_code(
+    asset_context: Vc<Box<dyn AssetContext>>,
+    enable_hmr: bool,
+) -> Result<Vc<Code>> {
+    let runtime_dir = if enable_hmr { "dev" } else { "prod" };
+    let chunk_loading = &*asset_context
+        .compile_time_info()
+        .environment()
+        .chunk_loading()
+        .await?;
+
+    Ok(embed_static_code(
+        asset_context,
+        match chunk_loading {
+            ChunkLoading::None => format!("{runtime_dir}/runtime/none/runtime-backend-none.ts"),
+            ChunkLoading::NodeJs => {
+                format!("{runtime_dir}/runtime/nodejs/runtime-backend-nodejs.ts")
+            }
+            ChunkLoading::Dom => format!("{runtime_dir}/runtime/dom/runtime-backend-dom.ts"),
+            ChunkLoading::Edge => format!("{runtime_dir}/runtime/edge/runtime-backend-edge.ts"),
+            ChunkLoading::Deno => format!("{runtime_dir}/runtime/deno/runtime-backend-deno.ts"),
+            ChunkLoading::Bun => format!("{runtime_dir}/runtime/bun/runtime-backend-bun.ts"),
+        },
+    ))
+}
+
+/// Records the version of this crate on the global object, so the runtime that
+/// produced a bundle can be identified when debugging.
+fn runtime_version_marker() -> String {
+    format!(
+        "globalThis.__TURBOPACK_RUNTIME_VERSION = {};",
+        StringifyJs(env!("CARGO_PKG_VERSION"))
+    )
+}
+
+/// Makes sure a non-empty chunk base path ends with exactly one `/`, so the
+/// runtime can append chunk paths to it directly.
+fn normalize_chunk_base_path(chunk_base_path: &str) -> String {
+    if chunk_base_path.is_empty() {
+        return String::new();
+    }
+    format!("{}/", chunk_base_path.trim_end_matches('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{future::Future, io::Write};
+
+    use anyhow::{Context, Result};
+    use indoc::{formatdoc, indoc};
+    use turbo_tasks::{trace::TraceRawVcs, TurboTasks, Value, Vc};
+    use turbo_tasks_memory::MemoryBackend;
+    use turbopack_core::{
+        code_builder::{Code, CodeBuilder},
+        environment::{
+            BrowserEnvironment, BunEnvironment, DenoEnvironment, EdgeWorkerEnvironment,
+            Environment, ExecutionEnvironment,
+        },
+        source_map::GenerateSourceMap,
+    };
+
+    use super::{
+        get_browser_runtime_code, get_prod_runtime_code, get_runtime_backend_code,
+        normalize_chunk_base_path, runtime_version_marker,
+    };
+    use crate::{
+        asset_context::get_runtime_asset_context, BrowserRuntimeOptions, RuntimeOutputFormat,
+    };
+
+    async fn run<T: TraceRawVcs + Send + 'static>(
+        future: impl Future<Output = Result<T>> + Send + 'static,
+    ) -> Result<T> {
+        crate::register();
+        TurboTasks::new(MemoryBackend::default())
+            .run_once(future)
+            .await
+    }
+
+    fn browser_environment(browserslist_query: &str) -> Vc<Environment> {
+        Environment::new(Value::new(ExecutionEnvironment::Browser(
+            BrowserEnvironment {
+                dom: true,
+                web_worker: false,
+                service_worker: false,
+                browserslist_query: browserslist_query.to_string(),
+            }
+            .into(),
+        )))
+    }
+
+    fn runtime_code(environment: Vc<Environment>, options: BrowserRuntimeOptions) -> Vc<Code> {
+        get_browser_runtime_code(environment, Vc::cell("output".to_string()), options.cell())
+    }
+
+    async fn source_code(code: Vc<Code>) -> Result<String> {
+        Ok(code.await?.source_code().to_str()?.into_owned())
+    }
+
+    /// The constants the runtime starts with, after its bootstrap.
+    fn runtime_constants() -> String {
+        formatdoc!(
+            r#"
+                const CHUNK_BASE_PATH = "";
+                const RUNTIME_PUBLIC_PATH = "";
+                const PUBLIC_PATH = "";
+                const OUTPUT_ROOT = "output";
+                globalThis.__TURBOPACK_RUNTIME_VERSION = "{}";
+            "#,
+            env!("CARGO_PKG_VERSION")
+        )
+    }
+
+    async fn source_map(code: Vc<Code>) -> Result<String> {
+        let source_map = (*code.generate_source_map().await?).context("expected a source map")?;
+        Ok(source_map.to_rope().await?.to_str()?.into_owned())
+    }
+
+    #[test]
+    fn test_normalize_chunk_base_path() {
+        assert_eq!(normalize_chunk_base_path("assets"), "assets/");
+        assert_eq!(normalize_chunk_base_path("assets/"), "assets/");
+        assert_eq!(normalize_chunk_base_path("assets//"), "assets/");
+        assert_eq!(normalize_chunk_base_path("/"), "/");
+        assert_eq!(normalize_chunk_base_path(""), "");
+    }
+
+    #[test]
+    fn test_runtime_version_marker() {
+        assert_eq!(
+            runtime_version_marker(),
+            format!(
+                "globalThis.__TURBOPACK_RUNTIME_VERSION = \"{}\";",
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_prod_runtime_excludes_hmr() -> Result<()> {
+        let (dev, prod) = run(async {
+            let environment = browser_environment("Chrome 102");
+            Ok((
+                source_code(runtime_code(environment, Default::default())).await?,
+                source_code(get_prod_runtime_code(
+                    environment,
+                    Vc::cell("output".to_string()),
+                    BrowserRuntimeOptions::default().cell(),
+                ))
+                .await?,
+            ))
+        })
+        .await?;
+
+        for hmr_registration in ["function registerChunkList(", "TURBOPACK_CHUNK_LISTS"] {
+            assert!(dev.contains(hmr_registration));
+            assert!(!prod.contains(hmr_registration));
+        }
+        assert!(prod.contains(r#"globalThis["TURBOPACK"] = { push: registerChunk };"#));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_global_name() -> Result<()> {
+        let code = run(async {
+            source_code(runtime_code(
+                browser_environment("Chrome 102"),
+                BrowserRuntimeOptions {
+                    global_name: "__MFE1".to_string(),
+                    ..Default::default()
+                },
+            ))
+            .await
+        })
+        .await?;
+
+        assert!(code.contains(r#"if (!Array.isArray(globalThis["__MFE1"])) {"#));
+        assert!(code.contains(r#"const chunksToRegister = globalThis["__MFE1"];"#));
+        assert!(code.contains(r#"globalThis["__MFE1"] = { push: registerChunk };"#));
+        assert!(!code.contains(r#"globalThis["TURBOPACK"]"#));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_global_name_is_escaped() -> Result<()> {
+        let code = run(async {
+            source_code(runtime_code(
+                browser_environment("Chrome 102"),
+                BrowserRuntimeOptions {
+                    global_name: r#"a-b"];alert(1);//"#.to_string(),
+                    ..Default::default()
+                },
+            ))
+            .await
+        })
+        .await?;
+
+        assert!(code.contains(r#"const chunksToRegister = globalThis["a-b\"];alert(1);//"];"#));
+        assert!(!code.contains(r#"globalThis["a-b"];"#));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_edge_worker_backend() -> Result<()> {
+        let (inlined, loaded) = run(async {
+            let edge_worker = |load_chunks| {
+                Environment::new(Value::new(ExecutionEnvironment::EdgeWorker(
+                    EdgeWorkerEnvironment { load_chunks }.into(),
+                )))
+            };
+            Ok((
+                source_map(runtime_code(edge_worker(false), Default::default())).await?,
+                source_map(runtime_code(edge_worker(true), Default::default())).await?,
+            ))
+        })
+        .await?;
+
+        // Edge workers inline async imports unless they opt into loading chunks.
+        assert!(inlined.contains("[turbopack]/dev/runtime/none/runtime-backend-none.ts"));
+        assert!(!inlined.contains("[turbopack]/dev/runtime/edge/runtime-backend-edge.ts"));
+        assert!(loaded.contains("[turbopack]/dev/runtime/edge/runtime-backend-edge.ts"));
+        assert!(!loaded.contains("[turbopack]/dev/runtime/none/runtime-backend-none.ts"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_source_map_sections() -> Result<()> {
+        let source_map = run(async {
+            source_map(runtime_code(
+                browser_environment("Chrome 102"),
+                Default::default(),
+            ))
+            .await
+        })
+        .await?;
+
+        assert!(source_map.contains("\"sections\""));
+        for source in [
+            "[turbopack]/shared/runtime-utils.ts",
+            "[turbopack]/dev/runtime/base/runtime-base.ts",
+            "[turbopack]/dev/runtime/dom/runtime-backend-dom.ts",
+        ] {
+            assert!(source_map.contains(source), "missing section for {source}");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_extra_runtime_code_position() -> Result<()> {
+        let code = run(async {
+            let mut extra_runtime_code = CodeBuilder::default();
+            writeln!(extra_runtime_code, "/* extra runtime code */")?;
+            source_code(runtime_code(
+                browser_environment("Chrome 102"),
+                BrowserRuntimeOptions {
+                    extra_runtime_code: Some(Code::cell(extra_runtime_code.build())),
+                    ..Default::default()
+                },
+            ))
+            .await
+        })
+        .await?;
+
+        // `_eval` is the last function of the DOM backend.
+        let backend = code.find("function _eval(").context("missing backend")?;
+        let extra = code
+            .find("/* extra runtime code */")
+            .context("missing extra runtime code")?;
+        let registration = code
+            .find("const chunksToRegister")
+            .context("missing chunk registration")?;
+        assert!(backend < extra);
+        assert!(extra < registration);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_exclude_runtime_utils() -> Result<()> {
+        let (with_utils, without_utils, without_utils_source_map) = run(async {
+            let environment = browser_environment("Chrome 102");
+            let without_utils = runtime_code(
+                environment,
+                BrowserRuntimeOptions {
+                    include_runtime_utils: false,
+                    ..Default::default()
+                },
+            );
+            Ok((
+                source_code(runtime_code(environment, Default::default())).await?,
+                source_code(without_utils).await?,
+                source_map(without_utils).await?,
+            ))
+        })
+        .await?;
+
+        let runtime_utils = "const REEXPORTED_OBJECTS = Symbol(\"reexported objects\");";
+        assert!(with_utils.contains(runtime_utils));
+        assert!(!without_utils.contains(runtime_utils));
+        assert!(!without_utils_source_map.contains("[turbopack]/shared/runtime-utils.ts"));
+        assert!(without_utils_source_map.contains("[turbopack]/dev/runtime/base/runtime-base.ts"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_deno_backend() -> Result<()> {
+        let (code, source_map) = run(async {
+            let environment = Environment::new(Value::new(ExecutionEnvironment::Deno(
+                DenoEnvironment {}.into(),
+            )));
+            let code = runtime_code(environment, Default::default());
+            Ok((source_code(code).await?, source_map(code).await?))
+        })
+        .await?;
+
+        assert!(source_map.contains("[turbopack]/dev/runtime/deno/runtime-backend-deno.ts"));
+        // Chunk URLs are resolved against the chunk the runtime is part of.
+        assert!(code.contains("import.meta.url"));
+        assert!(!code.contains("Deno.mainModule"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_hmr_runtime_sources() -> Result<()> {
+        let (hmr, no_hmr) = run(async {
+            let environment = browser_environment("Chrome 102");
+            Ok((
+                source_map(runtime_code(environment, Default::default())).await?,
+                source_map(runtime_code(
+                    environment,
+                    BrowserRuntimeOptions {
+                        enable_hmr: false,
+                        ..Default::default()
+                    },
+                ))
+                .await?,
+            ))
+        })
+        .await?;
+
+        for (dev_source, prod_source) in [
+            (
+                "[turbopack]/dev/runtime/base/runtime-base.ts",
+                "[turbopack]/prod/runtime/base/runtime-base.ts",
+            ),
+            (
+                "[turbopack]/dev/runtime/dom/runtime-backend-dom.ts",
+                "[turbopack]/prod/runtime/dom/runtime-backend-dom.ts",
+            ),
+        ] {
+            assert!(hmr.contains(dev_source));
+            assert!(!hmr.contains(prod_source));
+            assert!(no_hmr.contains(prod_source));
+            assert!(!no_hmr.contains(dev_source));
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_backend_independent_of_base_path() -> Result<()> {
+        let (backend, runtimes) = run(async {
+            let environment = browser_environment("Chrome 102");
+            let backend = source_code(get_runtime_backend_code(
+                get_runtime_asset_context(environment),
+                true,
+            ))
+            .await?;
+            let mut runtimes = Vec::new();
+            for chunk_base_path in ["a", "b"] {
+                runtimes.push(
+                    source_code(runtime_code(
+                        environment,
+                        BrowserRuntimeOptions {
+                            chunk_base_path: Some(chunk_base_path.to_string()),
+                            ..Default::default()
+                        },
+                    ))
+                    .await?,
+                );
+            }
+            Ok((backend, runtimes))
+        })
+        .await?;
+
+        // Only the constants follow the base path, the backend is embedded
+        // unchanged.
+        for (runtime, chunk_base_path) in runtimes.iter().zip(["a/", "b/"]) {
+            assert!(runtime.contains(&format!("const CHUNK_BASE_PATH = \"{chunk_base_path}\";")));
+            assert!(runtime.contains(&backend));
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_bun_backend() -> Result<()> {
+        let (dev, prod) = run(async {
+            let environment = Environment::new(Value::new(ExecutionEnvironment::Bun(
+                BunEnvironment {}.into(),
+            )));
+            Ok((
+                source_map(runtime_code(environment, Default::default())).await?,
+                source_map(runtime_code(
+                    environment,
+                    BrowserRuntimeOptions {
+                        enable_hmr: false,
+                        ..Default::default()
+                    },
+                ))
+                .await?,
+            ))
+        })
+        .await?;
+
+        assert!(dev.contains("[turbopack]/dev/runtime/bun/runtime-backend-bun.ts"));
+        assert!(prod.contains("[turbopack]/prod/runtime/bun/runtime-backend-bun.ts"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_polyfills_for_legacy_environments() -> Result<()> {
+        let (legacy, modern) = run(async {
+            Ok((
+                source_map(runtime_code(
+                    browser_environment("Chrome 60"),
+                    Default::default(),
+                ))
+                .await?,
+                source_code(runtime_code(
+                    browser_environment("Chrome 102"),
+                    Default::default(),
+                ))
+                .await?,
+            ))
+        })
+        .await?;
+
+        for polyfill in [
+            "[turbopack]/shared/polyfills/global-this.ts",
+            "[turbopack]/shared/polyfills/promise-all-settled.ts",
+        ] {
+            assert!(legacy.contains(polyfill), "missing {polyfill}");
+        }
+        // Without polyfills, the runtime starts with its bootstrap.
+        assert!(modern.starts_with("(() => {\n"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_es_module_output_format() -> Result<()> {
+        let code = run(async {
+            source_code(runtime_code(
+                browser_environment("Chrome 102"),
+                BrowserRuntimeOptions {
+                    output_format: RuntimeOutputFormat::EsModule,
+                    ..Default::default()
+                },
+            ))
+            .await
+        })
+        .await?;
+
+        assert!(code.starts_with(&runtime_constants()));
+        assert!(code.ends_with(indoc! {r#"
+            const chunksToRegister = globalThis["TURBOPACK"];
+            if (Array.isArray(chunksToRegister)) {
+                globalThis["TURBOPACK"] = { push: registerChunk };
+                chunksToRegister.forEach(registerChunk);
+            }
+            export { registerChunk };
+        "#}));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_relaxed_bootstrap() -> Result<()> {
+        let code = run(async {
+            source_code(runtime_code(
+                browser_environment("Chrome 102"),
+                BrowserRuntimeOptions {
+                    strict_bootstrap: false,
+                    ..Default::default()
+                },
+            ))
+            .await
+        })
+        .await?;
+
+        let bootstrap = indoc! {r#"
+            (() => {
+            globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || [];
+
+        "#};
+        assert!(code.starts_with(&format!("{bootstrap}{}", runtime_constants())));
+        assert!(!code.contains(r#"if (!Array.isArray(globalThis["TURBOPACK"])) {"#));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_isolated_chunk_registration() -> Result<()> {
+        let code = run(async {
+            source_code(runtime_code(
+                browser_environment("Chrome 102"),
+                BrowserRuntimeOptions {
+                    isolate_chunk_errors: true,
+                    ..Default::default()
+                },
+            ))
+            .await
+        })
+        .await?;
+
+        assert!(code.ends_with(indoc! {r#"
+            function reportChunkError(error) {
+                const handler = globalThis.__TURBOPACK_CHUNK_ERROR_HANDLER__;
+                if (typeof handler === "function") {
+                    handler(error);
+                } else {
+                    console.error(error);
+                }
+            }
+            function registerChunkIsolated(chunk) {
+                try {
+                    Promise.resolve(registerChunk(chunk)).catch(reportChunkError);
+                } catch (error) {
+                    reportChunkError(error);
+                }
+            }
+            const chunksToRegister = globalThis["TURBOPACK"];
+            globalThis["TURBOPACK"] = { push: registerChunkIsolated };
+            chunksToRegister.forEach(registerChunkIsolated);
+            })();
+        "#}));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_public_path() -> Result<()> {
+        let code = run(async {
+            source_code(runtime_code(
+                browser_environment("Chrome 102"),
+                BrowserRuntimeOptions {
+                    chunk_base_path: Some("/_next".to_string()),
+                    public_path: Some("https://cdn.example.com/assets".to_string()),
+                    ..Default::default()
+                },
+            ))
+            .await
+        })
+        .await?;
+
+        assert!(code.contains("const CHUNK_BASE_PATH = \"/_next/\";"));
+        assert!(code.contains("const PUBLIC_PATH = \"https://cdn.example.com/assets/\";"));
+        // Chunk URLs are built from the public path.
+        assert!(code.contains("return `${PUBLIC_PATH}${chunkPath"));
+        Ok(())
+    }
+}
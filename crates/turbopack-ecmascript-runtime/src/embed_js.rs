@@ -1,3 +1,4 @@
+use anyhow::{bail, Result};
 use turbo_tasks::Vc;
 use turbo_tasks_fs::{embed_directory, FileContent, FileSystem, FileSystemPath};
 use turbopack_core::{code_builder::Code, context::AssetContext};
@@ -18,7 +19,61 @@ pub fn embed_file_path(path: String) -> Vc<FileSystemPath> {
     embed_fs().root().join(path)
 }
 
+/// Returns the code of an embedded runtime file. Fails early with the path of
+/// the file when it doesn't exist, e.g. when a new runtime backend is selected
+/// before its `.ts` file is added.
 #[turbo_tasks::function]
-pub fn embed_static_code(asset_context: Vc<Box<dyn AssetContext>>, path: String) -> Vc<Code> {
-    StaticEcmascriptCode::new(asset_context, embed_file_path(path)).code()
+pub async fn embed_static_code(
+    asset_context: Vc<Box<dyn AssetContext>>,
+    path: String,
+) -> Result<Vc<Code>> {
+    let file_path = embed_file_path(path.clone());
+    if let FileContent::NotFound = *file_path.read().await? {
+        bail!("embedded runtime file \"{path}\" does not exist");
+    }
+    Ok(StaticEcmascriptCode::new(asset_context, file_path).code())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use turbo_tasks::{TurboTasks, Value};
+    use turbo_tasks_memory::MemoryBackend;
+    use turbopack_core::environment::{BrowserEnvironment, Environment, ExecutionEnvironment};
+
+    use super::embed_static_code;
+    use crate::asset_context::get_runtime_asset_context;
+
+    #[tokio::test]
+    async fn test_missing_file_names_path() -> Result<()> {
+        crate::register();
+        let error = TurboTasks::new(MemoryBackend::default())
+            .run_once(async {
+                let environment = Environment::new(Value::new(ExecutionEnvironment::Browser(
+                    BrowserEnvironment {
+                        dom: true,
+                        web_worker: false,
+                        service_worker: false,
+                        browserslist_query: "Chrome 102".to_string(),
+                    }
+                    .into(),
+                )));
+                let code = embed_static_code(
+                    get_runtime_asset_context(environment),
+                    "dev/runtime/missing/runtime-backend-missing.ts".to_string(),
+                );
+                Ok(code.await.err().map(|error| format!("{error:#}")))
+            })
+            .await?;
+
+        let error = error.expect("embedding a missing file should fail");
+        assert!(
+            error.contains(
+                "embedded runtime file \"dev/runtime/missing/runtime-backend-missing.ts\" does \
+                 not exist"
+            ),
+            "{error}"
+        );
+        Ok(())
+    }
 }
@@ -1,18 +1,22 @@
 #![feature(arbitrary_self_types)]
 
 pub(crate) mod asset_context;
+pub(crate) mod browser_runtime_options;
 pub(crate) mod build_runtime;
 pub(crate) mod dev_runtime;
 #[cfg(feature = "test")]
 pub(crate) mod dummy_runtime;
 pub(crate) mod embed_js;
+pub(crate) mod output_format;
 pub(crate) mod runtime_type;
 
+pub use browser_runtime_options::BrowserRuntimeOptions;
 pub use build_runtime::get_nodejs_runtime_code;
-pub use dev_runtime::get_browser_runtime_code;
+pub use dev_runtime::{get_browser_runtime_code, get_prod_runtime_code};
 #[cfg(feature = "test")]
 pub use dummy_runtime::get_dummy_runtime_code;
 pub use embed_js::{embed_file, embed_file_path, embed_fs};
+pub use output_format::RuntimeOutputFormat;
 pub use runtime_type::RuntimeType;
 
 pub fn register() {
@@ -0,0 +1,14 @@
+use turbo_tasks::Vc;
+
+/// How the runtime code is wrapped.
+#[turbo_tasks::value(serialization = "auto_for_input")]
+#[derive(Debug, Default, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum RuntimeOutputFormat {
+    /// A classic script, wrapped in an immediately invoked function
+    /// expression so its declarations don't leak into the global scope.
+    #[default]
+    Iife,
+    /// An ECMAScript module using top-level statements, which exports the
+    /// chunk registration hook.
+    EsModule,
+}
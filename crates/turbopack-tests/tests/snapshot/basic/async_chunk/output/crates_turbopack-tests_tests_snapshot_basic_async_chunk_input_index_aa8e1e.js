@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_basic_async_chunk_input_index_aa8e1e.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_basic_async_chunk_input_4437c1._.js","output/crates_turbopack-tests_tests_snapshot_basic_async_chunk_input_import_a037e9.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/basic/async_chunk/input/index.js [test] (ecmascript)"]}
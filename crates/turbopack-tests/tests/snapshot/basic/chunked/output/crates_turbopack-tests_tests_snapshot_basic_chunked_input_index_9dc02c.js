@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_basic_chunked_input_index_9dc02c.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_basic_chunked_input_09b9a1._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/basic/chunked/input/index.js [test] (ecmascript)"]}
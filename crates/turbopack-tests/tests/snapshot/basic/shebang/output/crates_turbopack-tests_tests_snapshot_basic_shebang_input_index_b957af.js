@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_basic_shebang_input_index_b957af.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_basic_shebang_input_a87633._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/basic/shebang/input/index.js [test] (ecmascript)"]}
@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_basic_top-level-await_input_index_922e09.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_basic_top-level-await_input_3adb52._.js","output/crates_turbopack-tests_tests_snapshot_basic_top-level-await_input_UserAPI_0d64e4.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/basic/top-level-await/input/index.js [test] (ecmascript)"]}
@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_comptime_define_input_index_5931c6.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_comptime_define_input_index_49857f.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/comptime/define/input/index.js [test] (ecmascript)"]}
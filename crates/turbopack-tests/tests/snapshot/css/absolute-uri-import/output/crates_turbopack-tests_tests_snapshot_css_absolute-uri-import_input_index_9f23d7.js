@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_css_absolute-uri-import_input_index_9f23d7.js",
     {},
     {"otherChunks":[{"path":"output/crates_turbopack-tests_tests_snapshot_css_absolute-uri-import_input_90d01b._.css","included":["[project]/crates/turbopack-tests/tests/snapshot/css/absolute-uri-import/input/other.css [test] (css, )","[project]/crates/turbopack-tests/tests/snapshot/css/absolute-uri-import/input/withduplicateurl.css [test] (css, )","[project]/crates/turbopack-tests/tests/snapshot/css/absolute-uri-import/input/index.css [test] (css)"],"moduleChunks":["output/crates_turbopack-tests_tests_snapshot_css_absolute-uri-import_input_other_d96429.css","output/a587c_tests_snapshot_css_absolute-uri-import_input_withduplicateurl_d96429.css","output/crates_turbopack-tests_tests_snapshot_css_absolute-uri-import_input_index_7d7e1c.css","output/crates_turbopack-tests_tests_snapshot_css_absolute-uri-import_input_other_d96429.css","output/a587c_tests_snapshot_css_absolute-uri-import_input_withduplicateurl_d96429.css"]},"output/crates_turbopack-tests_tests_snapshot_css_absolute-uri-import_input_index_0e8055.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/css/absolute-uri-import/input/index.js [test] (ecmascript)"]}
@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_css_chained-attributes_input_index_e6e7e4.js",
     {},
     {"otherChunks":[{"path":"output/crates_turbopack-tests_tests_snapshot_css_chained-attributes_input_86e399._.css","included":["[project]/crates/turbopack-tests/tests/snapshot/css/chained-attributes/input/c.css [test] (css, layer(layer) layer(foo) print and (orientation: landscape) supports(not(display: inline-grid)) supports(font-format(woff2)))","[project]/crates/turbopack-tests/tests/snapshot/css/chained-attributes/input/c.css [test] (css, layer(layer) layer(bar) print and (orientation: portrait) supports(not(display: inline-grid)) supports(font-format(woff2)))","[project]/crates/turbopack-tests/tests/snapshot/css/chained-attributes/input/b.css [test] (css, layer(layer) print supports(not(display: inline-grid)) supports(font-format(woff2)))","[project]/crates/turbopack-tests/tests/snapshot/css/chained-attributes/input/a.css [test] (css, layer(layer) print supports(not(display: inline-grid)))","[project]/crates/turbopack-tests/tests/snapshot/css/chained-attributes/input/style.css [test] (css)"],"moduleChunks":["output/crates_turbopack-tests_tests_snapshot_css_chained-attributes_input_c_823b12.css","output/crates_turbopack-tests_tests_snapshot_css_chained-attributes_input_c_163c6a.css","output/crates_turbopack-tests_tests_snapshot_css_chained-attributes_input_b_dc1da6.css","output/crates_turbopack-tests_tests_snapshot_css_chained-attributes_input_a_bf62fc.css","output/crates_turbopack-tests_tests_snapshot_css_chained-attributes_input_style_7d7e1c.css","output/crates_turbopack-tests_tests_snapshot_css_chained-attributes_input_c_823b12.css","output/crates_turbopack-tests_tests_snapshot_css_chained-attributes_input_c_163c6a.css","output/crates_turbopack-tests_tests_snapshot_css_chained-attributes_input_b_dc1da6.css","output/crates_turbopack-tests_tests_snapshot_css_chained-attributes_input_a_bf62fc.css"]},"output/crates_turbopack-tests_tests_snapshot_css_chained-attributes_input_index_356166.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/css/chained-attributes/input/index.js [test] (ecmascript)"]}
@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_css_css-legacy-nesting_input_index_276773.js",
     {},
     {"otherChunks":[{"path":"output/crates_turbopack-tests_tests_snapshot_css_css-legacy-nesting_input_style_c557a7.css","included":["[project]/crates/turbopack-tests/tests/snapshot/css/css-legacy-nesting/input/style.css [test] (css)"],"moduleChunks":["output/crates_turbopack-tests_tests_snapshot_css_css-legacy-nesting_input_style_7d7e1c.css"]},"output/crates_turbopack-tests_tests_snapshot_css_css-legacy-nesting_input_index_8e60f2.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/css/css-legacy-nesting/input/index.js [test] (ecmascript)"]}
@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_css_css-modules_input_index_3c81e2.js",
     {},
     {"otherChunks":[{"path":"output/crates_turbopack-tests_tests_snapshot_css_css-modules_input_style_module_71f14f.css","included":["[project]/crates/turbopack-tests/tests/snapshot/css/css-modules/input/style.module.css [test] (css)"],"moduleChunks":["output/crates_turbopack-tests_tests_snapshot_css_css-modules_input_style_module_7d7e1c.css"]},"output/crates_turbopack-tests_tests_snapshot_css_css-modules_input_8abc52._.js","output/79fb1_turbopack-tests_tests_snapshot_css_css-modules_input_style_module_css_32764e._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/css/css-modules/input/index.js [test] (ecmascript)"]}
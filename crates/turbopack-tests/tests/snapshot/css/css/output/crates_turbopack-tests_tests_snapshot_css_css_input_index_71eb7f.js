@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_css_css_input_index_71eb7f.js",
     {},
     {"otherChunks":[{"path":"output/crates_turbopack-tests_tests_snapshot_css_css_input_f3a64b._.css","included":["[project]/crates/turbopack-tests/tests/snapshot/css/css/input/imported.css [test] (css, )","[project]/crates/turbopack-tests/tests/snapshot/css/css/input/imported.css [test] (css, layer(layer) print)","[project]/crates/turbopack-tests/tests/snapshot/css/css/input/style.css [test] (css)","[project]/crates/turbopack-tests/tests/snapshot/css/css/input/style.module.css [test] (css)","[project]/crates/turbopack-tests/tests/snapshot/css/css/input/node_modules/foo/style.css [test] (css)","[project]/crates/turbopack-tests/tests/snapshot/css/css/input/node_modules/foo/style.module.css [test] (css)"],"moduleChunks":["output/crates_turbopack-tests_tests_snapshot_css_css_input_imported_d96429.css","output/crates_turbopack-tests_tests_snapshot_css_css_input_imported_9f97d9.css","output/crates_turbopack-tests_tests_snapshot_css_css_input_style_7d7e1c.css","output/crates_turbopack-tests_tests_snapshot_css_css_input_style_module_7d7e1c.css","output/8697f_foo_style_7d7e1c.css","output/8697f_foo_style_module_7d7e1c.css","output/crates_turbopack-tests_tests_snapshot_css_css_input_imported_d96429.css","output/crates_turbopack-tests_tests_snapshot_css_css_input_imported_d96429.css","output/crates_turbopack-tests_tests_snapshot_css_css_input_imported_9f97d9.css"]},"output/crates_turbopack-tests_tests_snapshot_css_css_input_34944c._.js","output/8697f_foo_style_css_01e50f._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/css/css/input/index.js [test] (ecmascript)"]}
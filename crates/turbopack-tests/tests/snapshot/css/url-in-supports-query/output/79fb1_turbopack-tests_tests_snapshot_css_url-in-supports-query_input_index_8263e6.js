@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/79fb1_turbopack-tests_tests_snapshot_css_url-in-supports-query_input_index_8263e6.js",
     {},
     {"otherChunks":[{"path":"output/79fb1_turbopack-tests_tests_snapshot_css_url-in-supports-query_input_style_a5a67a.css","included":["[project]/crates/turbopack-tests/tests/snapshot/css/url-in-supports-query/input/style.css [test] (swc css)"],"moduleChunks":["output/79fb1_turbopack-tests_tests_snapshot_css_url-in-supports-query_input_style_4b6496.css"]},"output/79fb1_turbopack-tests_tests_snapshot_css_url-in-supports-query_input_index_c70a2c.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/css/url-in-supports-query/input/index.js [test] (ecmascript)"]}
@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_cssmodules_composes_input_index_607308.js",
     {},
     {"otherChunks":[{"path":"output/79fb1_turbopack-tests_tests_snapshot_cssmodules_composes_input_index_module_cb674a.css","included":["[project]/crates/turbopack-tests/tests/snapshot/cssmodules/composes/input/index.module.css [test] (css)"],"moduleChunks":["output/79fb1_turbopack-tests_tests_snapshot_cssmodules_composes_input_index_module_7d7e1c.css"]},"output/crates_turbopack-tests_tests_snapshot_cssmodules_composes_input_2ba052._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/cssmodules/composes/input/index.js [test] (ecmascript)"]}
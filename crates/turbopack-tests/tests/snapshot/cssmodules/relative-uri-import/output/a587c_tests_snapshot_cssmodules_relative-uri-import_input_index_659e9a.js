@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/a587c_tests_snapshot_cssmodules_relative-uri-import_input_index_659e9a.js",
     {},
     {"otherChunks":[{"path":"output/79fb1_turbopack-tests_tests_snapshot_cssmodules_relative-uri-import_input_6d6278._.css","included":["[project]/crates/turbopack-tests/tests/snapshot/cssmodules/relative-uri-import/input/other.module.css [test] (css)","[project]/crates/turbopack-tests/tests/snapshot/cssmodules/relative-uri-import/input/index.module.css [test] (css)"],"moduleChunks":["output/a587c_tests_snapshot_cssmodules_relative-uri-import_input_other_module_7d7e1c.css","output/a587c_tests_snapshot_cssmodules_relative-uri-import_input_index_module_7d7e1c.css"]},"output/79fb1_turbopack-tests_tests_snapshot_cssmodules_relative-uri-import_input_f065a6._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/cssmodules/relative-uri-import/input/index.js [test] (ecmascript)"]}
@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_env_env_input_index_6512b1.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_env_env_input_673035._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/env/env/input/.env/.env.js [test] (ecmascript)","[project]/crates/turbopack-tests/tests/snapshot/env/env/input/index.js [test] (ecmascript)"]}
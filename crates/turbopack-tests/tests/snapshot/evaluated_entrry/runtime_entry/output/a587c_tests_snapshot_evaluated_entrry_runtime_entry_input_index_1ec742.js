@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/a587c_tests_snapshot_evaluated_entrry_runtime_entry_input_index_1ec742.js",
     {},
     {"otherChunks":["output/a587c_tests_snapshot_evaluated_entrry_runtime_entry_input_index_97d560.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/evaluated_entrry/runtime_entry/input/index.js [test] (ecmascript)"]}
@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_example_example_input_index_86f5c3.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_example_example_input_index_838420.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/example/example/input/index.js [test] (ecmascript)"]}
@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_export-alls_cjs-2_input_index_d8a134.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_export-alls_cjs-2_input_2c57a6._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/export-alls/cjs-2/input/index.js [test] (ecmascript)"]}
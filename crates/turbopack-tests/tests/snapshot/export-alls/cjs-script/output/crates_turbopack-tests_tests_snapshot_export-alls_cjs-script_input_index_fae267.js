@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_export-alls_cjs-script_input_index_fae267.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_export-alls_cjs-script_input_ffd38d._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/export-alls/cjs-script/input/index.js [test] (ecmascript)"]}
@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_import-meta_cjs_input_index_cbf1c5.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_import-meta_cjs_input_44576c._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/import-meta/cjs/input/index.js [test] (ecmascript)"]}
@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/79fb1_turbopack-tests_tests_snapshot_import-meta_esm-multiple_input_index_bf1234.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_import-meta_esm-multiple_input_b94ed5._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/import-meta/esm-multiple/input/index.js [test] (ecmascript)"]}
@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_import-meta_esm-mutable_input_index_156a15.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_import-meta_esm-mutable_input_f730df._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/import-meta/esm-mutable/input/index.js [test] (ecmascript)"]}
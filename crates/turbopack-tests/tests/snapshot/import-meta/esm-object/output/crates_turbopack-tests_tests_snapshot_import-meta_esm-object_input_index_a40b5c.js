@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_import-meta_esm-object_input_index_a40b5c.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_import-meta_esm-object_input_51cbdd._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/import-meta/esm-object/input/index.js [test] (ecmascript)"]}
@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/79fb1_turbopack-tests_tests_snapshot_imports_duplicate-binding_input_index_be113b.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_imports_duplicate-binding_input_9ca1ac._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/imports/duplicate-binding/input/index.js [test] (ecmascript)"]}
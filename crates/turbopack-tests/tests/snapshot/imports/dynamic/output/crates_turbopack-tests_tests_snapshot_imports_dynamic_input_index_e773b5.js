@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_imports_dynamic_input_index_e773b5.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_imports_dynamic_input_index_5ee1a4.js","output/crates_turbopack-tests_tests_snapshot_imports_dynamic_input_vercel_mjs_b7663b._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/imports/dynamic/input/index.js [test] (ecmascript)"]}
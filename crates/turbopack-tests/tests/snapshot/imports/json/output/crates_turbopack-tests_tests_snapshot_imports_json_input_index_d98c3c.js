@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_imports_json_input_index_d98c3c.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_imports_json_input_22bb62._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/imports/json/input/index.js [test] (ecmascript)"]}
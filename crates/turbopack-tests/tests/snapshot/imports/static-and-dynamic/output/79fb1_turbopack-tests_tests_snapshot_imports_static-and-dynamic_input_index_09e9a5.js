@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/79fb1_turbopack-tests_tests_snapshot_imports_static-and-dynamic_input_index_09e9a5.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_imports_static-and-dynamic_input_cbb273._.js","output/a587c_tests_snapshot_imports_static-and-dynamic_input_vercel_mjs_aa3704._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/imports/static-and-dynamic/input/index.js [test] (ecmascript)"]}
@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_imports_static_input_index_300143.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_imports_static_input_4175d9._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/imports/static/input/index.js [test] (ecmascript)"]}
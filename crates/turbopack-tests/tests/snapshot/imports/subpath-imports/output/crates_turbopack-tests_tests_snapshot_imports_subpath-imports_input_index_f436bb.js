@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_imports_subpath-imports_input_index_f436bb.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_imports_subpath-imports_input_f5873d._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/imports/subpath-imports/input/index.js [test] (ecmascript)"]}
@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/79fb1_turbopack-tests_tests_snapshot_node_node_protocol_external_input_index_0fca49.js",
     {},
     {"otherChunks":["output/79fb1_turbopack-tests_tests_snapshot_node_node_protocol_external_input_index_8770aa.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/node/node_protocol_external/input/index.js [test] (ecmascript)"]}
@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_node_spawn_dynamic_input_index_2d1475.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_node_spawn_dynamic_input_e65664._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/node/spawn_dynamic/input/index.js [test] (ecmascript)"]}
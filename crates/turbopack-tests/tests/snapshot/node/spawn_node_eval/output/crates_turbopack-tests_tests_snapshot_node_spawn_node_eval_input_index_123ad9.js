@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/crates_turbopack-tests_tests_snapshot_node_spawn_node_eval_input_index_123ad9.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_node_spawn_node_eval_input_d0030e._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/node/spawn_node_eval/input/index.js [test] (ecmascript)"]}
@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/a587c_tests_snapshot_styled_components_styled_components_input_index_2f3cbd.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_ededc0._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/styled_components/styled_components/input/index.js [test] (ecmascript)"]}
@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/a587c_tests_snapshot_swc_transforms_mono_transforms_input_packages_app_index_754b2e.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_9cde7b._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/swc_transforms/mono_transforms/input/packages/app/index.js [test] (ecmascript)"]}
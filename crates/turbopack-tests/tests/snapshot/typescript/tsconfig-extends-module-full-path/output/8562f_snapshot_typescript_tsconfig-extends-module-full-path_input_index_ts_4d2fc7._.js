@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/8562f_snapshot_typescript_tsconfig-extends-module-full-path_input_index_ts_4d2fc7._.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_4a4ab7._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/typescript/tsconfig-extends-module-full-path/input/index.ts [test] (ecmascript)"]}
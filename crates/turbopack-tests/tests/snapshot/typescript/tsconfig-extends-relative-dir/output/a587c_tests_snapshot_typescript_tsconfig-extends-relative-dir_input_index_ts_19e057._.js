@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/a587c_tests_snapshot_typescript_tsconfig-extends-relative-dir_input_index_ts_19e057._.js",
     {},
     {"otherChunks":["output/a587c_tests_snapshot_typescript_tsconfig-extends-relative-dir_input_d34519._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/typescript/tsconfig-extends-relative-dir/input/index.ts [test] (ecmascript)"]}
@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/a587c_tests_snapshot_typescript_tsconfig-extends-without-ext_input_index_ts_bd75ee._.js",
     {},
     {"otherChunks":["output/a587c_tests_snapshot_typescript_tsconfig-extends-without-ext_input_69f4f4._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/typescript/tsconfig-extends-without-ext/input/index.ts [test] (ecmascript)"]}
@@ -1,4 +1,4 @@
-(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+(globalThis["TURBOPACK"] = globalThis["TURBOPACK"] || []).push([
     "output/a587c_tests_snapshot_typescript_tsconfig-extends_input_index_ts_3e0e16._.js",
     {},
     {"otherChunks":["output/crates_turbopack-tests_tests_snapshot_typescript_tsconfig-extends_input_72bd49._.js"],"runtimeModuleIds":["[project]/crates/turbopack-tests/tests/snapshot/typescript/tsconfig-extends/input/index.ts [test] (ecmascript)"]}
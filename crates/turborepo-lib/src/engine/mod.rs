@@ -562,8 +562,6 @@ impl fmt::Display for TaskNode {
 #[cfg(test)]
 mod test {
 
-    use std::collections::BTreeMap;
-
     use tempdir::TempDir;
     use turbopath::AbsoluteSystemPath;
     use turborepo_repository::{
@@ -591,15 +589,14 @@ mod test {
                     let package_json = path.join_component(&format!("{}.json", name));
 
                     let scripts = if had_build {
-                        BTreeMap::from_iter(
-                            [
-                                ("build".to_string(), "echo built!".to_string()),
-                                ("dev".to_string(), "echo running dev!".to_string()),
-                            ]
-                            .into_iter(),
-                        )
+                        [
+                            ("build".to_string(), "echo built!".to_string()),
+                            ("dev".to_string(), "echo running dev!".to_string()),
+                        ]
+                        .into_iter()
+                        .collect()
                     } else {
-                        BTreeMap::default()
+                        Default::default()
                     };
 
                     let package = PackageJson {
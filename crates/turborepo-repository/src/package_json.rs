@@ -1,10 +1,25 @@
-use std::{collections::BTreeMap, str::FromStr};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    io::Read,
+    ops::Range,
+    str::FromStr,
+    sync::Arc,
+};
 
 use anyhow::Result;
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use indexmap::IndexMap;
+use miette::{Diagnostic, SourceSpan};
+use serde::{
+    de::{self, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+use serde_json::{value::RawValue, Map, Value};
+use sha2::{Digest, Sha224, Sha256, Sha512};
 use turbopath::{AbsoluteSystemPath, RelativeUnixPathBuf};
 
+use crate::package_manager::PackageManager;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct PackageJson {
@@ -13,8 +28,24 @@ pub struct PackageJson {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<License>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repository: Option<Repository>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publish_config: Option<PublishConfig>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_field: Option<ModuleType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exports: Option<ExportsField>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub package_manager: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub workspaces: Option<Workspaces>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub dependencies: Option<BTreeMap<String, String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dev_dependencies: Option<BTreeMap<String, String>>,
@@ -22,14 +53,26 @@ pub struct PackageJson {
     pub optional_dependencies: Option<BTreeMap<String, String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub peer_dependencies: Option<BTreeMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peer_dependencies_meta: Option<BTreeMap<String, PeerDependencyMeta>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependencies_meta: Option<BTreeMap<String, DependencyMeta>>,
     #[serde(rename = "turbo", default, skip_serializing_if = "Option::is_none")]
     pub legacy_turbo_config: Option<Value>,
-    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
-    pub scripts: BTreeMap<String, String>,
+    // Kept in the author's order so that writing the manifest back doesn't
+    // reorder scripts
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub scripts: IndexMap<String, String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resolutions: Option<BTreeMap<String, String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub overrides: Option<Overrides>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pnpm: Option<PnpmConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catalog: Option<BTreeMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catalogs: Option<BTreeMap<String, BTreeMap<String, String>>>,
     // Unstructured fields kept for round trip capabilities
     #[serde(flatten)]
     pub other: BTreeMap<String, Value>,
@@ -40,24 +83,614 @@ pub struct PackageJson {
 pub struct PnpmConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub patched_dependencies: Option<BTreeMap<String, RelativeUnixPathBuf>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overrides: Option<BTreeMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package_extensions: Option<BTreeMap<String, PackageExtension>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peer_dependency_rules: Option<PeerDependencyRules>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_built_dependencies: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub never_built_dependencies: Option<Vec<String>>,
     // Unstructured config options kept for round trip capabilities
     #[serde(flatten)]
     pub other: BTreeMap<String, Value>,
 }
 
-#[derive(Debug, thiserror::Error)]
+/// Rules pnpm uses to relax peer dependency checks
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerDependencyRules {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_versions: Option<BTreeMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_deprecated_versions: Option<BTreeMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_missing: Option<Vec<String>>,
+    // Unstructured fields kept for round trip capabilities
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+/// Additional dependency information pnpm merges into a package's manifest
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageExtension {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependencies: Option<BTreeMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peer_dependencies: Option<BTreeMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peer_dependencies_meta: Option<BTreeMap<String, PeerDependencyMeta>>,
+    // Unstructured fields kept for round trip capabilities
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerDependencyMeta {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
+    // Unstructured fields kept for round trip capabilities
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+/// Settings from `publishConfig` that apply when the package is published
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registry: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    // Unstructured fields kept for round trip capabilities
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+/// Per dependency linking settings from `dependenciesMeta`
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyMeta {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub injected: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub built: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unplugged: Option<bool>,
+    // Unstructured fields kept for round trip capabilities
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+/// The `workspaces` field, either a list of globs or yarn classic's object
+/// form which can also carry `nohoist` patterns
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum Workspaces {
+    TopLevel(Vec<String>),
+    Nested {
+        #[serde(default)]
+        packages: Vec<String>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        nohoist: Vec<String>,
+        #[serde(flatten)]
+        other: BTreeMap<String, Value>,
+    },
+}
+
+impl AsRef<[String]> for Workspaces {
+    fn as_ref(&self) -> &[String] {
+        match self {
+            Workspaces::TopLevel(packages) => packages.as_slice(),
+            Workspaces::Nested { packages, .. } => packages.as_slice(),
+        }
+    }
+}
+
+impl From<Workspaces> for Vec<String> {
+    fn from(value: Workspaces) -> Self {
+        match value {
+            Workspaces::TopLevel(packages) => packages,
+            Workspaces::Nested { packages, .. } => packages,
+        }
+    }
+}
+
+/// The `license` field, either an SPDX expression or the deprecated
+/// `{ type, url }` object form
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum License {
+    Spdx(String),
+    Legacy {
+        #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+        kind: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        url: Option<String>,
+        // Unstructured fields kept for round trip capabilities
+        #[serde(flatten)]
+        other: BTreeMap<String, Value>,
+    },
+}
+
+impl License {
+    /// Returns the license identifier, which the object form keeps in `type`
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            License::Spdx(expression) => Some(expression),
+            License::Legacy { kind, .. } => kind.as_deref(),
+        }
+    }
+}
+
+/// The `repository` field, either the `github:user/repo` style shorthand or
+/// the full object form
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum Repository {
+    Shorthand(String),
+    Detailed {
+        #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+        kind: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        url: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        directory: Option<String>,
+        // Unstructured fields kept for round trip capabilities
+        #[serde(flatten)]
+        other: BTreeMap<String, Value>,
+    },
+}
+
+/// The `exports` field. It can be a single target, an array of fallback
+/// targets, or a map of either subpaths (keys starting with `.`) or conditions
+/// to further targets.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum ExportsField {
+    /// `null`, explicitly excludes a subpath or condition
+    Excluded,
+    Target(String),
+    Fallbacks(Vec<ExportsField>),
+    // Node tries conditions in the order they appear in, so it has to be kept
+    Map(IndexMap<String, ExportsField>),
+}
+
+impl ExportsField {
+    /// Resolves `subpath` (e.g. `.` or `./feature`) to a target path following
+    /// Node's conditional exports algorithm. Subpath patterns are not
+    /// supported. Like Node, condition maps are matched in their key order,
+    /// where `default` always matches.
+    pub fn resolve(&self, subpath: &str, conditions: &[&str]) -> Option<&str> {
+        match self {
+            ExportsField::Map(map) if map.keys().any(|key| key.starts_with('.')) => {
+                map.get(subpath)?.resolve_target(conditions)
+            }
+            _ if subpath == "." => self.resolve_target(conditions),
+            _ => None,
+        }
+    }
+
+    fn resolve_target(&self, conditions: &[&str]) -> Option<&str> {
+        match self {
+            ExportsField::Excluded => None,
+            ExportsField::Target(target) => Some(target),
+            ExportsField::Fallbacks(targets) => targets
+                .iter()
+                .find_map(|target| target.resolve_target(conditions)),
+            ExportsField::Map(map) => map
+                .iter()
+                .filter(|(condition, _)| {
+                    *condition == "default" || conditions.contains(&condition.as_str())
+                })
+                .find_map(|(_, target)| target.resolve_target(conditions)),
+        }
+    }
+}
+
+/// The module system from the `type` field that `.js` files are treated as
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ModuleType {
+    Module,
+    CommonJs,
+    /// A value Node doesn't know, kept so that the manifest still loads and
+    /// round trips
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// npm's `overrides`, either a version or a map of nested overrides. A nested
+/// map may use the `"."` key to set the version of the parent package.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum Overrides {
+    Version(String),
+    Nested(BTreeMap<String, Overrides>),
+}
+
+/// A single entry of pnpm's `overrides` with the selector split apart
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverrideEntry {
+    /// Name of the package being overridden
+    pub name: String,
+    /// Version range the override is restricted to, if the selector has one
+    pub range: Option<String>,
+    /// Version the package will be resolved to
+    pub version: String,
+}
+
+/// The package an `npm:` alias points at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AliasTarget {
+    pub name: String,
+    pub range: Option<String>,
+}
+
+/// A single entry of yarn's `resolutions` with its selector classified
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolutionRule {
+    pub selector: ResolutionSelector,
+    pub version: String,
+}
+
+/// The forms a `resolutions` selector can take
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolutionSelector {
+    /// `foo` or `@scope/foo`, pins every instance of the package
+    Package(String),
+    /// `parent/foo`, pins the last package only where it is reached through
+    /// the preceding ones
+    Nested(Vec<String>),
+    /// A selector we can't interpret yet, along with why
+    Unsupported { selector: String, reason: String },
+}
+
+/// The section of package.json a dependency was declared in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DependencyKind {
+    Dev,
+    Optional,
+    Regular,
+    Peer,
+}
+
+/// Byte ranges of package.json entries in the source they were parsed from
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpanMap {
+    /// The range of each script's JSON string literal, quotes included
+    pub scripts: BTreeMap<String, Range<usize>>,
+}
+
+/// Where the turbo configuration of a package should be read from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurboConfigSource {
+    /// There is no turbo configuration
+    None,
+    /// Only `turbo.json` exists
+    TurboJson,
+    /// Only the legacy `turbo` key in package.json exists
+    LegacyPackageJson,
+    /// Both exist. `turbo.json` wins and the legacy config is ignored
+    Conflict,
+}
+
+/// Structured differences between two package.json files
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageJsonDiff {
+    /// Dependency changes for every section that changed
+    pub dependencies: BTreeMap<DependencyKind, MapDiff>,
+    pub scripts: MapDiff,
+    /// Changes to top level string fields, keyed by their package.json name
+    pub fields: BTreeMap<String, Change<Option<String>>>,
+}
+
+/// Entries added, removed or changed between two string maps
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct MapDiff {
+    pub added: BTreeMap<String, String>,
+    pub removed: BTreeMap<String, String>,
+    pub changed: BTreeMap<String, Change<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Change<T> {
+    pub before: T,
+    pub after: T,
+}
+
+/// The protocol used by a dependency version specifier
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionProtocol {
+    /// `workspace:*`, `workspace:^1.0.0`, etc.
+    Workspace,
+    /// `catalog:` or `catalog:<name>`
+    Catalog,
+    /// `npm:<package>@<range>` aliases
+    NpmAlias,
+    /// `file:<path>`
+    File,
+    /// `link:<path>`
+    Link,
+    /// `git://`, `git+ssh://`, `git+https://`, `github:`, etc.
+    Git,
+    /// Tarballs fetched from a `http://` or `https://` URL
+    Url,
+    /// A semver range, exact version or dist-tag
+    Semver,
+}
+
+/// A problem encountered while parsing a package.json
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error, Diagnostic)]
+#[error("{path}: {message}")]
+#[diagnostic(code(package_json_parse_error))]
+pub struct ParseDiagnostic {
+    /// Path of the file the diagnostic was produced for
+    pub path: String,
+    /// Location of the offending value in the document, e.g. `.scripts`
+    pub json_path: String,
+    pub message: String,
+    #[source_code]
+    pub source_code: Arc<str>,
+    #[label]
+    pub span: Option<SourceSpan>,
+}
+
+/// Reasons a package name can fail npm's package name rules
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum NameError {
+    #[error("package name is missing")]
+    Missing,
+    #[error("package name cannot be empty")]
+    Empty,
+    #[error("package name cannot be longer than 214 characters, got {0}")]
+    TooLong(usize),
+    #[error("package name cannot start with a period")]
+    LeadingPeriod,
+    #[error("package name cannot start with an underscore")]
+    LeadingUnderscore,
+    #[error("package name cannot contain leading or trailing spaces")]
+    Whitespace,
+    #[error("package name cannot contain uppercase letters")]
+    Uppercase,
+    #[error("package name cannot contain '{0}'")]
+    InvalidCharacter(char),
+    #[error("scoped package name must be of the form @scope/name")]
+    InvalidScope,
+}
+
+const MAX_NAME_LENGTH: usize = 214;
+
+/// The `packageManager` field split into its parts, e.g.
+/// `pnpm@8.15.0+sha224.<hex digest>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageManagerSpec {
+    pub name: String,
+    pub version: String,
+    /// Corepack's optional integrity suffix
+    pub hash: Option<PackageManagerHash>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageManagerHash {
+    pub algorithm: String,
+    pub digest: String,
+}
+
+impl PackageManagerHash {
+    const ALGORITHMS: &'static [&'static str] = &["sha1", "sha224", "sha256", "sha384", "sha512"];
+
+    fn parse(suffix: &str) -> Option<Self> {
+        let (algorithm, digest) = suffix.split_once('.')?;
+        if !Self::ALGORITHMS.contains(&algorithm)
+            || digest.is_empty()
+            || !digest.chars().all(|c| c.is_ascii_hexdigit())
+        {
+            return None;
+        }
+        Some(Self {
+            algorithm: algorithm.to_string(),
+            digest: digest.to_string(),
+        })
+    }
+}
+
+/// Reasons a downloaded package manager can fail integrity verification
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum IntegrityError {
+    #[error("unsupported packageManager hash algorithm: {0}")]
+    UnsupportedAlgorithm(String),
+    #[error("packageManager {algorithm} hash mismatch, expected {expected}, got {actual}")]
+    Mismatch {
+        algorithm: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl PackageManagerSpec {
+    /// Parses a `packageManager` value, returning `None` if it isn't of the
+    /// form `<name>@<version>` accepted by package manager detection. A
+    /// `+<algorithm>.<hex digest>` suffix is only read as a hash for known
+    /// algorithms, any other `+` suffix is semver build metadata.
+    pub fn parse(spec: &str) -> Option<Self> {
+        let (name, _) = PackageManager::parse_package_manager_string(spec).ok()?;
+        let rest = spec.strip_prefix(name)?.strip_prefix('@')?;
+        let (version, hash) = match rest.rsplit_once('+') {
+            Some((version, suffix)) => match PackageManagerHash::parse(suffix) {
+                Some(hash) => (version, Some(hash)),
+                None => (rest, None),
+            },
+            None => (rest, None),
+        };
+        Some(Self {
+            name: name.to_string(),
+            version: version.to_string(),
+            hash,
+        })
+    }
+
+    /// Checks `downloaded` against the declared hash. Specs without a hash
+    /// have nothing to verify and always pass.
+    pub fn verify_against(&self, downloaded: &[u8]) -> Result<(), IntegrityError> {
+        let Some(hash) = &self.hash else {
+            return Ok(());
+        };
+        let actual = match hash.algorithm.as_str() {
+            "sha224" => hex::encode(Sha224::digest(downloaded)),
+            "sha256" => hex::encode(Sha256::digest(downloaded)),
+            "sha512" => hex::encode(Sha512::digest(downloaded)),
+            algorithm => return Err(IntegrityError::UnsupportedAlgorithm(algorithm.to_string())),
+        };
+        if !actual.eq_ignore_ascii_case(&hash.digest) {
+            return Err(IntegrityError::Mismatch {
+                algorithm: hash.algorithm.clone(),
+                expected: hash.digest.clone(),
+                actual,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error, Diagnostic)]
 pub enum Error {
     #[error("unable to read package.json: {0}")]
     Io(#[from] std::io::Error),
     #[error("unable to parse package.json: {0}")]
     Json(#[from] serde_json::Error),
+    #[error("unable to parse package.json")]
+    Parse(#[related] Vec<ParseDiagnostic>),
 }
 
 impl PackageJson {
     pub fn load(path: &AbsoluteSystemPath) -> Result<PackageJson, Error> {
         tracing::debug!("loading package.json from {}", path);
         let contents = path.read_to_string()?;
-        Self::from_str(&contents)
+        Self::load_from_str(&contents, path.as_str())
+    }
+
+    /// Same as `load`, but reads the file without blocking the executor
+    pub async fn load_async(path: &AbsoluteSystemPath) -> Result<PackageJson, Error> {
+        tracing::debug!("loading package.json from {}", path);
+        let contents = tokio::fs::read_to_string(path.as_std_path()).await?;
+        Self::load_from_str(&contents, path.as_str())
+    }
+
+    /// Parses the contents of a package.json, `path` is only used to annotate
+    /// diagnostics
+    pub fn load_from_str(contents: &str, path: &str) -> Result<PackageJson, Error> {
+        // Parsing straight into the struct takes a single pass. It fails on
+        // duplicate top level keys though, so only then do we take the slower
+        // path that resolves them the way `JSON.parse` does.
+        match serde_json::from_str(contents) {
+            Ok(package_json) => Ok(package_json),
+            Err(_) => {
+                let (package_json, _) = Self::load_from_str_with_warnings(contents, path)?;
+                Ok(package_json)
+            }
+        }
+    }
+
+    /// Same as `load_from_str`, but also returns where each script is defined
+    /// in `contents` so editors can jump to it
+    pub fn load_from_str_spanned(
+        contents: &str,
+        path: &str,
+    ) -> Result<(PackageJson, SpanMap), Error> {
+        let package_json = Self::load_from_str(contents, path)?;
+
+        // Raw values borrow from `contents`, which lets us recover their offsets.
+        // Parsing into a map rather than a struct keeps the last of any
+        // duplicate keys, matching how the package.json itself was parsed.
+        let offset = |raw: &RawValue| raw.get().as_ptr() as usize - contents.as_ptr() as usize;
+        let top_level: BTreeMap<String, &RawValue> = serde_json::from_str(contents)?;
+        let mut spans = SpanMap::default();
+        if let Some(scripts) = top_level.get("scripts") {
+            let scripts: BTreeMap<String, &RawValue> = serde_json::from_str(scripts.get())?;
+            spans.scripts = scripts
+                .into_iter()
+                .map(|(name, command)| {
+                    let start = offset(command);
+                    (name, start..start + command.get().len())
+                })
+                .collect();
+        }
+
+        Ok((package_json, spans))
+    }
+
+    /// Reads and parses a package.json from `reader`, `path_for_errors` is
+    /// only used to annotate diagnostics
+    pub fn load_from_reader(
+        mut reader: impl Read,
+        path_for_errors: &str,
+    ) -> Result<PackageJson, Error> {
+        let mut contents = String::new();
+        reader.read_to_string(&mut contents)?;
+        Self::load_from_str(&contents, path_for_errors)
+    }
+
+    /// Parses the contents of a package.json along with any recoverable
+    /// problems that were found. Duplicate keys are reported as warnings and
+    /// the last occurrence wins, matching `JSON.parse`.
+    pub fn load_from_str_with_warnings(
+        contents: &str,
+        path: &str,
+    ) -> Result<(PackageJson, Vec<ParseDiagnostic>), Error> {
+        let source_code: Arc<str> = contents.into();
+        let to_diagnostic = |err: serde_json::Error| {
+            Error::Parse(vec![ParseDiagnostic {
+                path: path.to_string(),
+                json_path: String::new(),
+                message: err.to_string(),
+                source_code: source_code.clone(),
+                span: error_span(contents, &err),
+            }])
+        };
+
+        let document: &RawValue = serde_json::from_str(contents).map_err(to_diagnostic)?;
+        let mut duplicates = Vec::new();
+        find_duplicate_keys(contents, document, "", &mut duplicates).map_err(to_diagnostic)?;
+
+        let package_json = if duplicates.is_empty() {
+            serde_json::from_str(contents)
+        } else {
+            // Blanking out the superseded entries keeps every other byte where
+            // it was, so errors still point at the right line and column.
+            let mut deduplicated = contents.as_bytes().to_vec();
+            for duplicate in &duplicates {
+                for byte in &mut deduplicated[duplicate.superseded.clone()] {
+                    if !matches!(byte, b'\n' | b'\r') {
+                        *byte = b' ';
+                    }
+                }
+            }
+            let deduplicated =
+                String::from_utf8(deduplicated).expect("only whole entries were blanked");
+            serde_json::from_str(&deduplicated)
+        }
+        .map_err(to_diagnostic)?;
+
+        let warnings = duplicates
+            .into_iter()
+            .map(|duplicate| ParseDiagnostic {
+                path: path.to_string(),
+                message: format!("duplicate key `{}`", duplicate.key),
+                json_path: duplicate.json_path,
+                source_code: source_code.clone(),
+                span: Some(duplicate.key_span.into()),
+            })
+            .collect();
+        Ok((package_json, warnings))
     }
 
     // Utility method for easy construction of package.json during testing
@@ -74,6 +707,165 @@ impl PackageJson {
             .chain(self.dependencies.iter().flatten())
     }
 
+    /// Like [PackageJson::all_dependencies], but also includes
+    /// `peerDependencies`
+    pub fn all_dependencies_including_peers(
+        &self,
+    ) -> impl Iterator<Item = (&String, &String)> + '_ {
+        self.all_dependencies()
+            .chain(self.peer_dependencies.iter().flatten())
+    }
+
+    /// Checks that `name` follows the npm package name rules
+    pub fn validate_name(&self) -> Result<(), NameError> {
+        let name = self.name.as_deref().ok_or(NameError::Missing)?;
+        if name.is_empty() {
+            return Err(NameError::Empty);
+        }
+        if name.len() > MAX_NAME_LENGTH {
+            return Err(NameError::TooLong(name.len()));
+        }
+        if name.trim() != name {
+            return Err(NameError::Whitespace);
+        }
+        if name.starts_with('.') {
+            return Err(NameError::LeadingPeriod);
+        }
+        if name.starts_with('_') {
+            return Err(NameError::LeadingUnderscore);
+        }
+
+        let parts = match name.strip_prefix('@') {
+            Some(scoped) => match scoped.split_once('/') {
+                Some((scope, package)) if !scope.is_empty() && !package.is_empty() => {
+                    vec![scope, package]
+                }
+                _ => return Err(NameError::InvalidScope),
+            },
+            None => vec![name],
+        };
+        for c in parts.into_iter().flat_map(|part| part.chars()) {
+            match c {
+                'a'..='z' | '0'..='9' | '-' | '_' | '.' => (),
+                'A'..='Z' => return Err(NameError::Uppercase),
+                c => return Err(NameError::InvalidCharacter(c)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns all declared dependencies along with the section they were
+    /// declared in. Unlike `all_dependencies` this includes
+    /// `peerDependencies`, callers can filter on the kind if they don't want
+    /// them.
+    pub fn all_dependencies_with_kind(
+        &self,
+    ) -> impl Iterator<Item = (DependencyKind, &String, &String)> + '_ {
+        fn with_kind(
+            kind: DependencyKind,
+            deps: &Option<BTreeMap<String, String>>,
+        ) -> impl Iterator<Item = (DependencyKind, &String, &String)> + '_ {
+            deps.iter()
+                .flatten()
+                .map(move |(name, version)| (kind, name, version))
+        }
+
+        with_kind(DependencyKind::Dev, &self.dev_dependencies)
+            .chain(with_kind(
+                DependencyKind::Optional,
+                &self.optional_dependencies,
+            ))
+            .chain(with_kind(DependencyKind::Regular, &self.dependencies))
+            .chain(with_kind(DependencyKind::Peer, &self.peer_dependencies))
+    }
+
+    /// Returns dependencies from any section that use the `workspace:`
+    /// protocol
+    pub fn workspace_dependencies(&self) -> impl Iterator<Item = (&String, &String)> + '_ {
+        self.all_dependencies_with_kind()
+            .filter(|(_, _, version)| Self::classify_version(version) == VersionProtocol::Workspace)
+            .map(|(_, name, version)| (name, version))
+    }
+
+    pub fn builder() -> PackageJsonBuilder {
+        PackageJsonBuilder::new()
+    }
+
+    /// Returns the parsed `packageManager` field if it is present and well
+    /// formed
+    pub fn package_manager_spec(&self) -> Option<PackageManagerSpec> {
+        PackageManagerSpec::parse(self.package_manager.as_deref()?)
+    }
+
+    /// Returns true if the package.json has a legacy `turbo` key
+    pub fn has_legacy_config(&self) -> bool {
+        self.legacy_turbo_config.is_some()
+    }
+
+    /// Decides which turbo configuration should be used given whether a
+    /// `turbo.json` exists next to this package.json. `turbo.json` always wins,
+    /// a [TurboConfigSource::Conflict] lets callers warn that the legacy
+    /// config is being ignored.
+    pub fn reconcile_turbo_config(&self, has_turbo_json: bool) -> TurboConfigSource {
+        match (has_turbo_json, self.has_legacy_config()) {
+            (false, false) => TurboConfigSource::None,
+            (true, false) => TurboConfigSource::TurboJson,
+            (false, true) => TurboConfigSource::LegacyPackageJson,
+            (true, true) => TurboConfigSource::Conflict,
+        }
+    }
+
+    /// Returns the workspace globs from either form of the `workspaces` field
+    pub fn workspace_globs(&self) -> &[String] {
+        match &self.workspaces {
+            Some(workspaces) => workspaces.as_ref(),
+            None => &[],
+        }
+    }
+
+    /// Returns true if this looks like the package.json at the root of a
+    /// workspace. Besides non-empty `workspaces`, the root is the only place
+    /// where pnpm reads its `pnpm` config and where turbo used to read its
+    /// legacy `turbo` config.
+    pub fn is_workspace_root(&self) -> bool {
+        !self.workspace_globs().is_empty() || self.pnpm.is_some() || self.has_legacy_config()
+    }
+
+    /// Returns true if the manifest declares no dependencies, scripts or
+    /// workspaces, e.g. one that only has a `name`
+    pub fn is_effectively_empty(&self) -> bool {
+        self.all_dependencies_with_kind().next().is_none()
+            && self.scripts.is_empty()
+            && self.workspace_globs().is_empty()
+    }
+
+    /// Returns yarn classic's `nohoist` patterns, which are only available in
+    /// the object form of the `workspaces` field
+    pub fn nohoist_patterns(&self) -> &[String] {
+        match &self.workspaces {
+            Some(Workspaces::Nested { nohoist, .. }) => nohoist,
+            _ => &[],
+        }
+    }
+
+    /// Returns the `publishConfig.access` level, e.g. `public` or `restricted`
+    pub fn publish_access(&self) -> Option<&str> {
+        self.publish_config.as_ref()?.access.as_deref()
+    }
+
+    /// Returns the module system declared by `type`, which is CommonJS when
+    /// the field is absent
+    pub fn module_type(&self) -> ModuleType {
+        self.type_field.clone().unwrap_or(ModuleType::CommonJs)
+    }
+
+    /// Returns the `files` globs that should be included when the package is
+    /// packed, or an empty slice if there are none
+    pub fn published_files(&self) -> &[String] {
+        self.files.as_deref().unwrap_or_default()
+    }
+
     /// Returns the command for script_name if it is non-empty
     pub fn command(&self, script_name: &str) -> Option<&str> {
         self.scripts
@@ -81,6 +873,654 @@ impl PackageJson {
             .filter(|command| !command.is_empty())
             .map(|command| command.as_str())
     }
+
+    /// Returns true if script_name exists and is non-empty
+    pub fn has_script(&self, script_name: &str) -> bool {
+        self.command(script_name).is_some()
+    }
+
+    /// Returns the non-empty scripts whose names match `pattern`, where `*`
+    /// matches any sequence of characters, sorted by script name
+    pub fn scripts_matching(&self, pattern: &str) -> Vec<(&str, &str)> {
+        let mut scripts: Vec<_> = self
+            .scripts
+            .keys()
+            .filter(|script_name| wildcard_match(pattern, script_name))
+            .filter_map(|script_name| Some((script_name.as_str(), self.command(script_name)?)))
+            .collect();
+        scripts.sort_unstable();
+        scripts
+    }
+
+    /// Returns the names of the scripts whose command contains `binary` as
+    /// whitespace-delimited words, so `rm` matches `rm -rf dist` but not
+    /// `rmdir dist`. `binary` may span several words, e.g. `rm -rf`.
+    pub fn scripts_referencing(&self, binary: &str) -> Vec<&str> {
+        let needle: Vec<_> = binary.split_whitespace().collect();
+        if needle.is_empty() {
+            return Vec::new();
+        }
+        self.scripts
+            .iter()
+            .filter(|(_, command)| {
+                let words: Vec<_> = command.split_whitespace().collect();
+                words.windows(needle.len()).any(|window| window == needle)
+            })
+            .map(|(script_name, _)| script_name.as_str())
+            .collect()
+    }
+
+    /// Returns the `pre<name>`, `<name>` and `post<name>` scripts in the order
+    /// npm would run them, skipping any that are missing or empty
+    pub fn script_sequence(&self, name: &str) -> Vec<(&str, &str)> {
+        [
+            format!("pre{name}"),
+            name.to_string(),
+            format!("post{name}"),
+        ]
+        .into_iter()
+        .filter_map(|script_name| {
+            let command = self.command(&script_name)?;
+            let (script_name, _) = self.scripts.get_key_value(&script_name)?;
+            Some((script_name.as_str(), command))
+        })
+        .collect()
+    }
+
+    /// Flattens npm `overrides` into `(path, version)` pairs where the path is
+    /// the chain of package names joined by `>`, e.g. `foo>bar`
+    pub fn flatten_overrides(&self) -> Vec<(String, String)> {
+        fn walk(path: &str, overrides: &Overrides, flattened: &mut Vec<(String, String)>) {
+            match overrides {
+                Overrides::Version(version) => {
+                    if !path.is_empty() {
+                        flattened.push((path.to_string(), version.clone()));
+                    }
+                }
+                Overrides::Nested(nested) => {
+                    for (name, overrides) in nested {
+                        let child_path = match (name.as_str(), path) {
+                            (".", path) => path.to_string(),
+                            (name, "") => name.to_string(),
+                            (name, path) => format!("{path}>{name}"),
+                        };
+                        walk(&child_path, overrides, flattened);
+                    }
+                }
+            }
+        }
+
+        let mut flattened = Vec::new();
+        if let Some(overrides) = &self.overrides {
+            walk("", overrides, &mut flattened);
+        }
+        flattened
+    }
+
+    /// Returns a single view of the version pins declared via yarn's
+    /// `resolutions` and npm's `overrides`, keyed by the `>` separated paths
+    /// of [PackageJson::flatten_overrides]. Resolution selectors such as
+    /// `parent/foo` are converted to that form, while ones we can't interpret,
+    /// like globs, are kept as written. If a path appears in both, the version
+    /// from `overrides` wins.
+    pub fn effective_overrides(&self) -> BTreeMap<String, String> {
+        let mut effective: BTreeMap<_, _> = self
+            .parsed_resolutions()
+            .into_iter()
+            .map(|rule| {
+                let path = match rule.selector {
+                    ResolutionSelector::Package(name) => name,
+                    ResolutionSelector::Nested(names) => names.join(">"),
+                    ResolutionSelector::Unsupported { selector, .. } => selector,
+                };
+                (path, rule.version)
+            })
+            .collect();
+        effective.extend(self.flatten_overrides());
+        effective
+    }
+
+    /// Returns [PackageJson::effective_overrides] with each target classified
+    /// by its protocol, so pins such as `workspace:*` can be linked locally
+    /// instead of being looked up in the registry
+    pub fn effective_overrides_resolved(&self) -> Vec<(String, VersionProtocol)> {
+        self.effective_overrides()
+            .into_iter()
+            .map(|(path, version)| (path, Self::classify_version(&version)))
+            .collect()
+    }
+
+    /// Returns yarn's `resolutions` with each selector split into the package
+    /// names along its path. Selectors using glob patterns such as `**/foo`
+    /// are returned as [ResolutionSelector::Unsupported] rather than dropped.
+    pub fn parsed_resolutions(&self) -> Vec<ResolutionRule> {
+        self.resolutions
+            .iter()
+            .flatten()
+            .map(|(selector, version)| ResolutionRule {
+                selector: parse_resolution_selector(selector),
+                version: version.clone(),
+            })
+            .collect()
+    }
+
+    /// Returns the real package behind an `npm:<name>@<range>` alias, or `None`
+    /// if `spec` isn't an alias
+    pub fn resolve_alias(spec: &str) -> Option<AliasTarget> {
+        let target = spec.trim().strip_prefix("npm:")?;
+        let (name, range) = split_selector(target);
+        if name.is_empty() {
+            return None;
+        }
+        Some(AliasTarget {
+            name: name.to_string(),
+            range: range
+                .filter(|range| !range.is_empty())
+                .map(|range| range.to_string()),
+        })
+    }
+
+    /// Classifies a dependency version specifier by its protocol
+    pub fn classify_version(spec: &str) -> VersionProtocol {
+        let spec = spec.trim();
+        if spec.starts_with("workspace:") {
+            VersionProtocol::Workspace
+        } else if spec.starts_with("catalog:") {
+            VersionProtocol::Catalog
+        } else if spec.starts_with("npm:") {
+            VersionProtocol::NpmAlias
+        } else if spec.starts_with("file:") {
+            VersionProtocol::File
+        } else if spec.starts_with("link:") {
+            VersionProtocol::Link
+        } else if spec.starts_with("git") {
+            VersionProtocol::Git
+        } else if spec.starts_with("http://") || spec.starts_with("https://") {
+            VersionProtocol::Url
+        } else {
+            VersionProtocol::Semver
+        }
+    }
+
+    /// Parses `version` as a semver version. Returns `None` if the package has
+    /// no version and an error if the version isn't valid semver.
+    pub fn semver_version(&self) -> Option<Result<node_semver::Version, node_semver::SemverError>> {
+        self.version.as_deref().map(node_semver::Version::parse)
+    }
+
+    /// Looks up the version of `pkg` in a pnpm catalog. `None` or `"default"`
+    /// refers to the default catalog, any other name to a named catalog.
+    pub fn resolve_catalog(&self, name: Option<&str>, pkg: &str) -> Option<&str> {
+        let catalog = match name {
+            None | Some("default") => self
+                .catalog
+                .as_ref()
+                .or_else(|| self.catalogs.as_ref()?.get("default")),
+            Some(name) => self.catalogs.as_ref()?.get(name),
+        }?;
+        catalog.get(pkg).map(|version| version.as_str())
+    }
+
+    /// Returns a SHA-256 hash of the canonical JSON form of the package.json.
+    /// Keys are sorted and insignificant whitespace is dropped so manifests
+    /// that only differ in formatting or key order hash identically.
+    pub fn content_hash(&self) -> String {
+        let value = serde_json::to_value(self).expect("package.json is always serializable");
+        let canonical = serde_json::to_string(&canonicalize(value))
+            .expect("json values are always serializable");
+        hex::encode(Sha256::digest(canonical.as_bytes()))
+    }
+
+    /// Returns the dependency, script and field changes needed to turn this
+    /// package.json into `other`
+    pub fn diff(&self, other: &PackageJson) -> PackageJsonDiff {
+        let empty = BTreeMap::new();
+        let dependencies = [
+            (
+                DependencyKind::Dev,
+                &self.dev_dependencies,
+                &other.dev_dependencies,
+            ),
+            (
+                DependencyKind::Optional,
+                &self.optional_dependencies,
+                &other.optional_dependencies,
+            ),
+            (
+                DependencyKind::Regular,
+                &self.dependencies,
+                &other.dependencies,
+            ),
+            (
+                DependencyKind::Peer,
+                &self.peer_dependencies,
+                &other.peer_dependencies,
+            ),
+        ]
+        .into_iter()
+        .map(|(kind, before, after)| {
+            let diff = MapDiff::new(
+                before.as_ref().unwrap_or(&empty),
+                after.as_ref().unwrap_or(&empty),
+            );
+            (kind, diff)
+        })
+        .filter(|(_, diff)| !diff.is_empty())
+        .collect();
+
+        let license = |package_json: &PackageJson| {
+            package_json
+                .license
+                .as_ref()
+                .and_then(License::name)
+                .map(str::to_string)
+        };
+        let (license_before, license_after) = (license(self), license(other));
+        let fields = [
+            ("name", &self.name, &other.name),
+            ("version", &self.version, &other.version),
+            ("description", &self.description, &other.description),
+            ("license", &license_before, &license_after),
+            (
+                "packageManager",
+                &self.package_manager,
+                &other.package_manager,
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, before, after)| before != after)
+        .map(|(field, before, after)| {
+            (
+                field.to_string(),
+                Change {
+                    before: before.clone(),
+                    after: after.clone(),
+                },
+            )
+        })
+        .collect();
+
+        let sorted_scripts = |package_json: &PackageJson| -> BTreeMap<String, String> {
+            package_json.scripts.clone().into_iter().collect()
+        };
+
+        PackageJsonDiff {
+            dependencies,
+            scripts: MapDiff::new(&sorted_scripts(self), &sorted_scripts(other)),
+            fields,
+        }
+    }
+}
+
+impl MapDiff {
+    fn new(before: &BTreeMap<String, String>, after: &BTreeMap<String, String>) -> Self {
+        let mut diff = MapDiff::default();
+        for (key, before_value) in before {
+            match after.get(key) {
+                None => {
+                    diff.removed.insert(key.clone(), before_value.clone());
+                }
+                Some(after_value) if after_value != before_value => {
+                    diff.changed.insert(
+                        key.clone(),
+                        Change {
+                            before: before_value.clone(),
+                            after: after_value.clone(),
+                        },
+                    );
+                }
+                Some(_) => {}
+            }
+        }
+        for (key, after_value) in after {
+            if !before.contains_key(key) {
+                diff.added.insert(key.clone(), after_value.clone());
+            }
+        }
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Assembles a [PackageJson] one field at a time
+#[derive(Debug, Clone, Default)]
+pub struct PackageJsonBuilder {
+    package_json: PackageJson,
+}
+
+impl PackageJsonBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.package_json.name = Some(name.into());
+        self
+    }
+
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.package_json.version = Some(version.into());
+        self
+    }
+
+    /// Adds a dependency to the section matching `kind`
+    pub fn add_dependency(
+        mut self,
+        kind: DependencyKind,
+        name: impl Into<String>,
+        version: impl Into<String>,
+    ) -> Self {
+        let deps = match kind {
+            DependencyKind::Dev => &mut self.package_json.dev_dependencies,
+            DependencyKind::Optional => &mut self.package_json.optional_dependencies,
+            DependencyKind::Regular => &mut self.package_json.dependencies,
+            DependencyKind::Peer => &mut self.package_json.peer_dependencies,
+        };
+        deps.get_or_insert_with(BTreeMap::new)
+            .insert(name.into(), version.into());
+        self
+    }
+
+    pub fn script(mut self, name: impl Into<String>, command: impl Into<String>) -> Self {
+        self.package_json
+            .scripts
+            .insert(name.into(), command.into());
+        self
+    }
+
+    pub fn build(self) -> PackageJson {
+        self.package_json
+    }
+}
+
+// Rebuilds every object with its keys in sorted order. We can't rely on
+// serde_json's default map ordering as `preserve_order` may be enabled by
+// another crate in the workspace.
+fn canonicalize(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries = map.into_iter().collect::<Vec<_>>();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, canonicalize(value)))
+                    .collect::<Map<_, _>>(),
+            )
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(canonicalize).collect()),
+        value => value,
+    }
+}
+
+impl PnpmConfig {
+    /// Returns the entries of `overrides` with any `name@range` selectors
+    /// split into their package name and range
+    pub fn parsed_overrides(&self) -> Vec<OverrideEntry> {
+        self.overrides
+            .iter()
+            .flatten()
+            .map(|(selector, version)| {
+                let (name, range) = split_selector(selector);
+                OverrideEntry {
+                    name: name.to_string(),
+                    range: range.map(|range| range.to_string()),
+                    version: version.clone(),
+                }
+            })
+            .collect()
+    }
+}
+
+// Matches `name` against a pattern in which `*` stands for any, possibly
+// empty, sequence of characters.
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    // `split` always yields at least one part
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts = parts.peekable();
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() {
+            return rest.len() >= part.len() && rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+    rest.is_empty()
+}
+
+// Splits a resolutions selector on `/`, keeping scoped package names such as
+// `@scope/foo` together as one segment.
+fn parse_resolution_selector(selector: &str) -> ResolutionSelector {
+    let unsupported = |reason: &str| ResolutionSelector::Unsupported {
+        selector: selector.to_string(),
+        reason: reason.to_string(),
+    };
+    if selector.contains('*') {
+        return unsupported("glob patterns in resolutions are not supported");
+    }
+
+    let mut segments = Vec::new();
+    let mut parts = selector.split('/');
+    while let Some(part) = parts.next() {
+        let segment = if part.starts_with('@') {
+            match parts.next() {
+                Some(name) if !name.is_empty() => format!("{part}/{name}"),
+                _ => return unsupported("scoped package name is missing its name"),
+            }
+        } else {
+            part.to_string()
+        };
+        if segment.is_empty() {
+            return unsupported("empty package name in resolution path");
+        }
+        segments.push(segment);
+    }
+
+    match segments.len() {
+        1 => ResolutionSelector::Package(segments.remove(0)),
+        _ => ResolutionSelector::Nested(segments),
+    }
+}
+
+// Splits `name@range` into its parts, taking care not to split on the leading
+// `@` of a scoped package name.
+fn split_selector(selector: &str) -> (&str, Option<&str>) {
+    let search_start = usize::from(selector.starts_with('@'));
+    match selector[search_start..].find('@') {
+        Some(idx) => {
+            let idx = idx + search_start;
+            (&selector[..idx], Some(&selector[idx + 1..]))
+        }
+        None => (selector, None),
+    }
+}
+
+// Converts the 1-based line and column of a serde_json error into a span in
+// `contents`. Errors from `from_value` carry no position, so they don't get
+// one.
+fn error_span(contents: &str, err: &serde_json::Error) -> Option<SourceSpan> {
+    if err.line() == 0 {
+        return None;
+    }
+    let line_offset = contents
+        .split_inclusive('\n')
+        .take(err.line() - 1)
+        .map(|line| line.len())
+        .sum::<usize>();
+    let offset = (line_offset + err.column().saturating_sub(1)).min(contents.len());
+    Some((offset, 1.min(contents.len() - offset)).into())
+}
+
+// A key that appears more than once in the same object
+struct DuplicateKey {
+    key: String,
+    json_path: String,
+    // The repeated occurrence of the key
+    key_span: Range<usize>,
+    // The previous entry for the key, including its trailing comma. The last
+    // occurrence of a key wins, so this one gets ignored.
+    superseded: Range<usize>,
+}
+
+// The members of a JSON value, borrowed from the document
+enum Members<'a> {
+    Object(Vec<(String, &'a RawValue)>),
+    Array(Vec<&'a RawValue>),
+    Scalar,
+}
+
+impl<'de> Deserialize<'de> for Members<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(MembersVisitor)
+    }
+}
+
+struct MembersVisitor;
+
+impl<'de> Visitor<'de> for MembersVisitor {
+    type Value = Members<'de>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("any JSON value")
+    }
+
+    fn visit_bool<E: de::Error>(self, _: bool) -> Result<Self::Value, E> {
+        Ok(Members::Scalar)
+    }
+
+    fn visit_i64<E: de::Error>(self, _: i64) -> Result<Self::Value, E> {
+        Ok(Members::Scalar)
+    }
+
+    fn visit_u64<E: de::Error>(self, _: u64) -> Result<Self::Value, E> {
+        Ok(Members::Scalar)
+    }
+
+    fn visit_f64<E: de::Error>(self, _: f64) -> Result<Self::Value, E> {
+        Ok(Members::Scalar)
+    }
+
+    fn visit_str<E: de::Error>(self, _: &str) -> Result<Self::Value, E> {
+        Ok(Members::Scalar)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Members::Scalar)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut elements = Vec::new();
+        while let Some(element) = seq.next_element()? {
+            elements.push(element);
+        }
+        Ok(Members::Array(elements))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut entries = Vec::new();
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+        Ok(Members::Object(entries))
+    }
+}
+
+// Records every object in `value` that contains the same key more than once.
+// Values borrow from `contents`, which lets us recover their offsets.
+fn find_duplicate_keys(
+    contents: &str,
+    value: &RawValue,
+    json_path: &str,
+    duplicates: &mut Vec<DuplicateKey>,
+) -> Result<(), serde_json::Error> {
+    match serde_json::from_str(value.get())? {
+        Members::Object(entries) => {
+            let mut previous = HashMap::new();
+            for (key, value) in entries {
+                let json_path = format!("{json_path}.{key}");
+                let entry = entry_span(contents, value);
+                if let Some(superseded) = previous.insert(key.clone(), entry.clone()) {
+                    duplicates.push(DuplicateKey {
+                        key,
+                        json_path: json_path.clone(),
+                        key_span: entry.start..key_end(contents, entry.start),
+                        superseded,
+                    });
+                }
+                find_duplicate_keys(contents, value, &json_path, duplicates)?;
+            }
+        }
+        Members::Array(elements) => {
+            for (index, element) in elements.into_iter().enumerate() {
+                find_duplicate_keys(
+                    contents,
+                    element,
+                    &format!("{json_path}[{index}]"),
+                    duplicates,
+                )?;
+            }
+        }
+        Members::Scalar => {}
+    }
+    Ok(())
+}
+
+// Returns the span of the object entry holding `value`, from the opening quote
+// of its key through the comma that follows it, if any
+fn entry_span(contents: &str, value: &RawValue) -> Range<usize> {
+    let bytes = contents.as_bytes();
+    let value_start = value.get().as_ptr() as usize - contents.as_ptr() as usize;
+    let value_end = value_start + value.get().len();
+
+    // Walk back over the `:` to the closing quote of the key, then to the first
+    // quote that isn't escaped.
+    let mut start = contents[..value_start]
+        .trim_end()
+        .strip_suffix(':')
+        .map_or(value_start, |before_colon| before_colon.trim_end().len());
+    start = start.saturating_sub(1);
+    while start > 0 {
+        start -= 1;
+        let backslashes = bytes[..start]
+            .iter()
+            .rev()
+            .take_while(|byte| **byte == b'\\')
+            .count();
+        if bytes[start] == b'"' && backslashes % 2 == 0 {
+            break;
+        }
+    }
+
+    let after_value = &contents[value_end..];
+    let trailing = after_value.len() - after_value.trim_start().len();
+    let end = if after_value.trim_start().starts_with(',') {
+        value_end + trailing + 1
+    } else {
+        value_end
+    };
+    start..end
+}
+
+// Returns the end of the key whose opening quote is at `start`
+fn key_end(contents: &str, start: usize) -> usize {
+    let mut escaped = false;
+    for (offset, byte) in contents.as_bytes()[start + 1..].iter().enumerate() {
+        match byte {
+            b'\\' => escaped = !escaped,
+            b'"' if !escaped => return start + offset + 2,
+            _ => escaped = false,
+        }
+    }
+    contents.len()
 }
 
 impl FromStr for PackageJson {
@@ -103,12 +1543,306 @@ mod test {
     #[test_case(json!({"name": "foo", "resolutions": {"foo": "1.0.0"}}) ; "berry resolutions")]
     #[test_case(json!({"name": "foo", "pnpm": {"patchedDependencies": {"some-pkg": "./patchfile"}, "another-field": 1}}) ; "pnpm")]
     #[test_case(json!({"name": "foo", "pnpm": {"another-field": 1}}) ; "pnpm without patches")]
+    #[test_case(json!({"name": "foo", "overrides": {"foo": "1.0.0", "bar": {".": "2.0.0", "baz": {"qux": "3.0.0"}}}}) ; "npm overrides")]
+    #[test_case(json!({"name": "foo", "description": "a package", "license": "MIT", "repository": "github:vercel/turbo"}) ; "repository shorthand")]
+    #[test_case(json!({"name": "foo", "repository": {"type": "git", "url": "https://github.com/vercel/turbo.git", "directory": "packages/foo"}}) ; "repository object")]
+    #[test_case(json!({"name": "foo", "repository": {"type": "git", "url": "https://github.com/vercel/turbo.git", "web": "https://github.com/vercel/turbo"}}) ; "repository object with extra keys")]
+    #[test_case(json!({"name": "foo", "license": {"type": "MIT", "url": "https://opensource.org/licenses/MIT"}}) ; "legacy license object")]
+    #[test_case(json!({"name": "foo", "pnpm": {"peerDependencyRules": {"ignoreMissing": ["@types/*"], "allowedVersions": {"react": "18"}, "allowedDeprecatedVersions": {"request": "*"}}}}) ; "pnpm peer dependency rules")]
+    #[test_case(json!({"name": "foo", "files": ["dist", "src/**/*.d.ts"]}) ; "files")]
+    #[test_case(json!({"name": "foo", "exports": {".": {"import": "./index.mjs", "require": ["./index.cjs", null]}, "./package.json": "./package.json"}}) ; "exports")]
+    #[test_case(json!({"name": "foo", "catalog": {"react": "^18"}, "catalogs": {"legacy": {"react": "^17"}}}) ; "catalogs")]
+    #[test_case(json!({"name": "foo", "pnpm": {"overrides": {"foo": "1.0.0", "bar@^2": "2.1.0"}}}) ; "pnpm overrides")]
+    #[test_case(json!({"name": "foo", "pnpm": {"onlyBuiltDependencies": ["esbuild"], "neverBuiltDependencies": ["fsevents"]}}) ; "pnpm build allowlists")]
+    #[test_case(json!({"name": "foo", "workspaces": ["packages/*"]}) ; "workspaces")]
+    #[test_case(json!({"name": "foo", "workspaces": {"packages": ["packages/*"], "nohoist": ["**/react-native"]}}) ; "workspaces with nohoist")]
+    #[test_case(json!({"name": "foo", "peerDependencies": {"react": "^18"}, "peerDependenciesMeta": {"react": {"optional": true}}}) ; "peer dependencies meta")]
     fn test_roundtrip(json: Value) {
         let package_json: PackageJson = serde_json::from_value(json.clone()).unwrap();
         let actual = serde_json::to_value(package_json).unwrap();
         assert_eq!(actual, json);
     }
 
+    #[test]
+    fn test_unknown_fields_survive_mutation() -> Result<()> {
+        let mut package_json = PackageJson::from_value(json!({
+            "name": "foo",
+            "version": "1.0.0",
+            "volta": {"node": "20.11.0", "pnpm": "8.15.0"}
+        }))?;
+        package_json.version = Some("1.1.0".to_string());
+
+        let actual = serde_json::to_value(&package_json)?;
+        assert_eq!(
+            actual,
+            json!({
+                "name": "foo",
+                "version": "1.1.0",
+                "volta": {"node": "20.11.0", "pnpm": "8.15.0"}
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_script_sequence() -> Result<()> {
+        let package_json = PackageJson::from_value(json!({
+            "scripts": {
+                "prebuild": "rimraf dist",
+                "build": "tsc",
+                "pretest": "",
+                "test": "jest",
+                "posttest": "echo done"
+            }
+        }))?;
+        assert_eq!(
+            package_json.script_sequence("build"),
+            vec![("prebuild", "rimraf dist"), ("build", "tsc")]
+        );
+        assert_eq!(
+            package_json.script_sequence("test"),
+            vec![("test", "jest"), ("posttest", "echo done")]
+        );
+        assert!(package_json.script_sequence("lint").is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_scripts_order_preserved() -> Result<()> {
+        let contents = r#"{"scripts":{"test":"jest","build":"tsc","dev":"next dev"}}"#;
+        let package_json = PackageJson::load_from_str(contents, "package.json")?;
+        assert_eq!(
+            package_json.scripts.keys().collect::<Vec<_>>(),
+            vec!["test", "build", "dev"]
+        );
+        assert_eq!(serde_json::to_string(&package_json)?, contents);
+        Ok(())
+    }
+
+    #[test_case("build", true ; "present")]
+    #[test_case("lint", false ; "present but empty")]
+    #[test_case("test", false ; "missing")]
+    fn test_has_script(script_name: &str, expected: bool) {
+        let package_json =
+            PackageJson::from_value(json!({"scripts": {"build": "tsc", "lint": ""}})).unwrap();
+        assert_eq!(package_json.has_script(script_name), expected);
+    }
+
+    #[test_case("test:*", &[("test:e2e", "playwright test"), ("test:unit", "jest")] ; "prefix")]
+    #[test_case("build", &[("build", "tsc")] ; "exact name")]
+    #[test_case("*:unit", &[("test:unit", "jest")] ; "suffix")]
+    #[test_case("lint:*", &[] ; "no matches")]
+    fn test_scripts_matching(pattern: &str, expected: &[(&str, &str)]) {
+        let package_json = PackageJson::from_value(json!({
+            "scripts": {
+                "build": "tsc",
+                "test": "jest --all",
+                "test:unit": "jest",
+                "test:e2e": "playwright test",
+                "test:lint": ""
+            }
+        }))
+        .unwrap();
+        assert_eq!(package_json.scripts_matching(pattern), expected);
+    }
+
+    #[test_case("rm", &["clean", "purge"] ; "single word")]
+    #[test_case("rm -rf", &["clean"] ; "multiple words")]
+    #[test_case("rmdir", &["tidy"] ; "distinct binary")]
+    #[test_case("curl", &[] ; "no matches")]
+    #[test_case("", &[] ; "empty")]
+    fn test_scripts_referencing(binary: &str, expected: &[&str]) {
+        let package_json = PackageJson::from_value(json!({
+            "scripts": {
+                "clean": "rm -rf dist",
+                "tidy": "rmdir dist",
+                "purge": "tsc --build --clean && rm dist/index.js"
+            }
+        }))
+        .unwrap();
+        assert_eq!(package_json.scripts_referencing(binary), expected);
+    }
+
+    #[test_case(json!({"type": "module"}), ModuleType::Module ; "module")]
+    #[test_case(json!({"type": "commonjs"}), ModuleType::CommonJs ; "commonjs")]
+    #[test_case(json!({}), ModuleType::CommonJs ; "absent")]
+    #[test_case(json!({"type": "esm"}), ModuleType::Other("esm".to_string()) ; "unknown")]
+    fn test_module_type(json: Value, expected: ModuleType) {
+        let package_json = PackageJson::from_value(json.clone()).unwrap();
+        assert_eq!(package_json.module_type(), expected);
+        assert_eq!(serde_json::to_value(&package_json).unwrap(), json);
+    }
+
+    #[test]
+    fn test_flatten_overrides() -> Result<()> {
+        let package_json = PackageJson::from_value(json!({
+            "overrides": {
+                "foo": "1.0.0",
+                "bar": {
+                    ".": "2.0.0",
+                    "baz": "3.0.0"
+                }
+            }
+        }))?;
+        assert_eq!(
+            package_json.flatten_overrides(),
+            vec![
+                ("bar".to_string(), "2.0.0".to_string()),
+                ("bar>baz".to_string(), "3.0.0".to_string()),
+                ("foo".to_string(), "1.0.0".to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test_case(json!({"version": "1.2.3"}), Some(Ok("1.2.3")) ; "valid version")]
+    #[test_case(json!({"version": "1.2.3-beta.1"}), Some(Ok("1.2.3-beta.1")) ; "prerelease")]
+    #[test_case(json!({"version": "not-a-version"}), Some(Err(())) ; "garbage")]
+    #[test_case(json!({}), None ; "missing")]
+    fn test_semver_version(json: Value, expected: Option<Result<&str, ()>>) {
+        let package_json = PackageJson::from_value(json).unwrap();
+        let actual = package_json
+            .semver_version()
+            .map(|version| version.map(|version| version.to_string()).map_err(|_| ()));
+        assert_eq!(
+            actual,
+            expected.map(|version| version.map(|version| version.to_string()))
+        );
+    }
+
+    #[test]
+    fn test_repository_forms() -> Result<()> {
+        let package_json = PackageJson::from_value(json!({"repository": "github:vercel/turbo"}))?;
+        assert_eq!(
+            package_json.repository,
+            Some(Repository::Shorthand("github:vercel/turbo".to_string()))
+        );
+
+        let package_json = PackageJson::from_value(json!({
+            "repository": {"type": "git", "url": "https://github.com/vercel/turbo.git"}
+        }))?;
+        assert_eq!(
+            package_json.repository,
+            Some(Repository::Detailed {
+                kind: Some("git".to_string()),
+                url: Some("https://github.com/vercel/turbo.git".to_string()),
+                directory: None,
+                other: BTreeMap::new(),
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_license_forms() -> Result<()> {
+        let package_json = PackageJson::from_value(json!({"license": "(MIT OR Apache-2.0)"}))?;
+        let license = package_json.license.unwrap();
+        assert_eq!(license, License::Spdx("(MIT OR Apache-2.0)".to_string()));
+        assert_eq!(license.name(), Some("(MIT OR Apache-2.0)"));
+
+        let package_json = PackageJson::from_value(json!({
+            "license": {"type": "ISC", "url": "https://opensource.org/licenses/ISC"}
+        }))?;
+        let license = package_json.license.unwrap();
+        assert_eq!(
+            license,
+            License::Legacy {
+                kind: Some("ISC".to_string()),
+                url: Some("https://opensource.org/licenses/ISC".to_string()),
+                other: BTreeMap::new(),
+            }
+        );
+        assert_eq!(license.name(), Some("ISC"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_workspaces_nohoist() -> Result<()> {
+        let package_json = PackageJson::from_value(json!({
+            "workspaces": {
+                "packages": ["packages/*"],
+                "nohoist": ["**/react-native", "**/react-native/**"]
+            }
+        }))?;
+        assert_eq!(package_json.workspace_globs(), ["packages/*"]);
+        assert_eq!(
+            package_json.nohoist_patterns(),
+            ["**/react-native", "**/react-native/**"]
+        );
+
+        let package_json = PackageJson::from_value(json!({"workspaces": ["apps/*"]}))?;
+        assert_eq!(package_json.workspace_globs(), ["apps/*"]);
+        assert!(package_json.nohoist_patterns().is_empty());
+
+        assert!(PackageJson::default().workspace_globs().is_empty());
+        Ok(())
+    }
+
+    #[test_case(json!({}), true ; "nothing")]
+    #[test_case(json!({"name": "foo"}), true ; "name only")]
+    #[test_case(json!({"name": "foo", "scripts": {"build": "tsc"}}), false ; "single script")]
+    #[test_case(json!({"peerDependencies": {"react": "^18"}}), false ; "peer dependency")]
+    #[test_case(json!({"workspaces": ["packages/*"]}), false ; "workspaces")]
+    fn test_is_effectively_empty(json: Value, expected: bool) {
+        let package_json = PackageJson::from_value(json).unwrap();
+        assert_eq!(package_json.is_effectively_empty(), expected);
+    }
+
+    #[test_case(json!({"name": "web", "dependencies": {"react": "^18"}}), false ; "app package")]
+    #[test_case(json!({"name": "root", "workspaces": ["apps/*", "packages/*"]}), true ; "workspace root")]
+    #[test_case(json!({"name": "root", "workspaces": {"packages": ["apps/*"]}}), true ; "nested workspaces")]
+    #[test_case(json!({"name": "root", "workspaces": []}), false ; "empty workspaces")]
+    #[test_case(json!({"name": "root", "pnpm": {"overrides": {"foo": "1.0.0"}}}), true ; "pnpm config")]
+    #[test_case(json!({"name": "root", "turbo": {}}), true ; "legacy turbo config")]
+    fn test_is_workspace_root(json: Value, expected: bool) {
+        let package_json = PackageJson::from_value(json).unwrap();
+        assert_eq!(package_json.is_workspace_root(), expected);
+    }
+
+    #[test]
+    fn test_published_files() -> Result<()> {
+        let package_json = PackageJson::from_value(json!({"files": ["dist", "*.d.ts"]}))?;
+        assert_eq!(package_json.published_files(), ["dist", "*.d.ts"]);
+        assert!(PackageJson::default().published_files().is_empty());
+        Ok(())
+    }
+
+    #[test_case(json!("./index.js"), ".", &["import"], Some("./index.js") ; "string sugar")]
+    #[test_case(json!("./index.js"), "./feature", &["import"], None ; "string sugar only exports root")]
+    #[test_case(json!({".": "./index.js", "./feature": "./feature.js"}), ".", &[], Some("./index.js") ; "dot key")]
+    #[test_case(json!({".": "./index.js", "./feature": "./feature.js"}), "./feature", &[], Some("./feature.js") ; "subpath key")]
+    #[test_case(json!({"import": "./index.mjs", "require": "./index.cjs"}), ".", &["require"], Some("./index.cjs") ; "conditions sugar")]
+    #[test_case(json!({".": {"import": "./index.mjs", "require": "./index.cjs"}}), ".", &["import", "require"], Some("./index.mjs") ; "conditional entry")]
+    #[test_case(json!({".": {"node": {"import": "./node.mjs"}, "default": "./index.js"}}), ".", &["node", "require"], Some("./index.js") ; "nested conditions fall through to default")]
+    #[test_case(json!({".": {"import": "./index.mjs"}}), ".", &["require"], None ; "no matching condition")]
+    #[test_case(json!({"./internal": null}), "./internal", &[], None ; "excluded subpath")]
+    #[test_case(json!({".": ["./missing", "./index.js"]}), ".", &[], Some("./missing") ; "fallbacks")]
+    fn test_exports_resolve(
+        exports: Value,
+        subpath: &str,
+        conditions: &[&str],
+        expected: Option<&str>,
+    ) {
+        let exports: ExportsField = serde_json::from_value(exports).unwrap();
+        assert_eq!(exports.resolve(subpath, conditions), expected);
+    }
+
+    #[test]
+    fn test_exports_resolve_follows_key_order() -> Result<()> {
+        let exports: ExportsField = serde_json::from_str(
+            r#"{".": {"require": "./index.cjs", "import": "./index.mjs", "default": "./index.js"}}"#,
+        )?;
+        assert_eq!(
+            exports.resolve(".", &["import", "require"]),
+            Some("./index.cjs")
+        );
+
+        let exports: ExportsField =
+            serde_json::from_str(r#"{"default": "./index.js", "import": "./index.mjs"}"#)?;
+        assert_eq!(exports.resolve(".", &["import"]), Some("./index.js"));
+        Ok(())
+    }
+
     #[test]
     fn test_legacy_turbo_config() -> Result<()> {
         let contents = r#"{"turbo": {}}"#;
@@ -119,7 +1853,620 @@ mod test {
         let contents = r#"{"turbo": { "globalDependencies": [".env"] } }"#;
         let package_json = serde_json::from_str::<PackageJson>(contents)?;
 
-        assert!(package_json.legacy_turbo_config.is_some());
+        assert_eq!(
+            package_json
+                .legacy_turbo_config
+                .as_ref()
+                .map(|config| &config["globalDependencies"]),
+            Some(&serde_json::json!([".env"]))
+        );
+
+        Ok(())
+    }
+
+    #[test_case(json!({}), false, TurboConfigSource::None ; "neither")]
+    #[test_case(json!({}), true, TurboConfigSource::TurboJson ; "turbo.json only")]
+    #[test_case(json!({"turbo": {}}), false, TurboConfigSource::LegacyPackageJson ; "legacy only")]
+    #[test_case(json!({"turbo": {}}), true, TurboConfigSource::Conflict ; "both")]
+    fn test_reconcile_turbo_config(json: Value, has_turbo_json: bool, expected: TurboConfigSource) {
+        let package_json = PackageJson::from_value(json).unwrap();
+        assert_eq!(
+            package_json.reconcile_turbo_config(has_turbo_json),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_peer_dependencies_meta() -> Result<()> {
+        let package_json = PackageJson::from_value(json!({
+            "peerDependenciesMeta": {"react": {"optional": true}}
+        }))?;
+        assert_eq!(
+            package_json.peer_dependencies_meta,
+            Some(BTreeMap::from([(
+                "react".to_string(),
+                PeerDependencyMeta {
+                    optional: Some(true),
+                    ..Default::default()
+                }
+            )]))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_dependencies_meta_round_trip() -> Result<()> {
+        let json = json!({
+            "name": "foo",
+            "dependenciesMeta": {"shared": {"injected": true}}
+        });
+        let package_json = PackageJson::from_value(json.clone())?;
+        assert_eq!(
+            package_json.dependencies_meta,
+            Some(BTreeMap::from([(
+                "shared".to_string(),
+                DependencyMeta {
+                    injected: Some(true),
+                    ..Default::default()
+                }
+            )]))
+        );
+        assert_eq!(serde_json::to_value(&package_json)?, json);
+        Ok(())
+    }
+
+    #[test]
+    fn test_publish_config() -> Result<()> {
+        let json = json!({
+            "name": "@acme/ui",
+            "publishConfig": {
+                "registry": "https://npm.pkg.github.com",
+                "access": "public",
+                "tag": "next",
+                "directory": "dist"
+            }
+        });
+        let package_json = PackageJson::from_value(json.clone())?;
+        let publish_config = package_json.publish_config.as_ref().unwrap();
+        assert_eq!(
+            publish_config.registry.as_deref(),
+            Some("https://npm.pkg.github.com")
+        );
+        assert_eq!(publish_config.tag.as_deref(), Some("next"));
+        assert_eq!(publish_config.other["directory"], json!("dist"));
+        assert_eq!(package_json.publish_access(), Some("public"));
+        assert_eq!(serde_json::to_value(&package_json)?, json);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pnpm_package_extensions() -> Result<()> {
+        let json = json!({
+            "name": "foo",
+            "pnpm": {
+                "packageExtensions": {
+                    "react-redux@1": {
+                        "peerDependencies": {"react": "*"},
+                        "peerDependenciesMeta": {"react": {"optional": true}}
+                    }
+                }
+            }
+        });
+        let package_json = PackageJson::from_value(json.clone())?;
+        let extension = package_json
+            .pnpm
+            .as_ref()
+            .and_then(|pnpm| pnpm.package_extensions.as_ref())
+            .and_then(|extensions| extensions.get("react-redux@1"))
+            .unwrap();
+        assert_eq!(
+            extension.peer_dependencies,
+            Some([("react".to_string(), "*".to_string())].into())
+        );
+        assert_eq!(
+            extension.peer_dependencies_meta.as_ref().unwrap()["react"].optional,
+            Some(true)
+        );
+        assert_eq!(serde_json::to_value(package_json)?, json);
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_with_warnings() -> Result<()> {
+        let contents = r#"{
+            "name": "foo",
+            "scripts": {"build": "tsc"},
+            "pnpm": {"overrides": {"a": "1", "a": "2"}},
+            "scripts": {"build": "tsc --build"}
+        }"#;
+        let (package_json, warnings) =
+            PackageJson::load_from_str_with_warnings(contents, "package.json")?;
+
+        assert_eq!(package_json.command("build"), Some("tsc --build"));
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|warning| (warning.json_path.as_str(), warning.message.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (".pnpm.overrides.a", "duplicate key `a`"),
+                (".scripts", "duplicate key `scripts`"),
+            ]
+        );
+        let span = warnings[1].span.expect("warning should point at the key");
+        assert_eq!(
+            &contents[span.offset()..span.offset() + span.len()],
+            r#""scripts""#
+        );
+        assert_eq!(
+            contents[..span.offset()].lines().count(),
+            5,
+            "the repeated key should be highlighted"
+        );
+        assert_eq!(
+            PackageJson::load_from_str(contents, "package.json")?,
+            package_json
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_from_str_spanned() -> Result<()> {
+        let contents = r#"{
+            "name": "foo",
+            "scripts": {
+                "build": "tsc --build",
+                "test": "jest"
+            }
+        }"#;
+        let (package_json, spans) = PackageJson::load_from_str_spanned(contents, "package.json")?;
+
+        assert_eq!(package_json.command("build"), Some("tsc --build"));
+        assert_eq!(
+            &contents[spans.scripts["build"].clone()],
+            r#""tsc --build""#
+        );
+        assert_eq!(&contents[spans.scripts["test"].clone()], r#""jest""#);
+
+        let (_, spans) = PackageJson::load_from_str_spanned(r#"{"name": "foo"}"#, "package.json")?;
+        assert!(spans.scripts.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error_has_span() {
+        let contents = "{\n  \"name\": \"foo\",\n}";
+        let Err(Error::Parse(diagnostics)) = PackageJson::load_from_str(contents, "package.json")
+        else {
+            panic!("expected a parse error");
+        };
+        let span = diagnostics[0].span.expect("diagnostic should carry a span");
+        assert_eq!(&contents[span.offset()..], "}");
+        assert_eq!(&*diagnostics[0].source_code, contents);
+    }
+
+    #[test]
+    fn test_type_error_has_span() {
+        let contents = "{\n  \"name\": \"foo\",\n  \"scripts\": 5\n}";
+        let Err(Error::Parse(diagnostics)) = PackageJson::load_from_str(contents, "package.json")
+        else {
+            panic!("expected a parse error");
+        };
+        assert!(diagnostics[0].message.contains("invalid type"));
+        let span = diagnostics[0].span.expect("diagnostic should carry a span");
+        assert_eq!(
+            contents[..span.offset()].lines().last(),
+            Some("  \"scripts\": "),
+            "the offending value should be highlighted"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_async() -> Result<()> {
+        let tmp = tempfile::tempdir()?;
+        let dir = AbsoluteSystemPath::from_std_path(tmp.path())?;
+        let path = dir.join_component("package.json");
+        path.create_with_contents(r#"{"name": "foo", "scripts": {"build": "tsc"}}"#)?;
+
+        let package_json = PackageJson::load_async(&path).await?;
+        assert_eq!(package_json, PackageJson::load(&path)?);
+        assert_eq!(package_json.command("build"), Some("tsc"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_from_reader() -> Result<()> {
+        let contents = br#"{"name": "foo", "version": "1.0.0"}"#;
+        let package_json = PackageJson::load_from_reader(&contents[..], "foo/package.json")?;
+        assert_eq!(package_json.name.as_deref(), Some("foo"));
+        assert_eq!(package_json.version.as_deref(), Some("1.0.0"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_all_dependencies_including_peers() -> Result<()> {
+        let package_json = PackageJson::from_value(json!({
+            "dependencies": {"foo": "^1.0.0"},
+            "devDependencies": {"bar": "^2.0.0"},
+            "peerDependencies": {"react": "^18"}
+        }))?;
+        let names = |deps: Vec<(&String, &String)>| {
+            deps.into_iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            names(package_json.all_dependencies().collect()),
+            vec!["bar", "foo"]
+        );
+        assert_eq!(
+            names(package_json.all_dependencies_including_peers().collect()),
+            vec!["bar", "foo", "react"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_all_dependencies_with_kind() -> Result<()> {
+        let package_json = PackageJson::from_value(json!({
+            "dependencies": {"foo": "^1.0.0"},
+            "devDependencies": {"foo": "^1.1.0", "bar": "^2.0.0"},
+            "peerDependencies": {"baz": "*"}
+        }))?;
+        let foo_kinds = package_json
+            .all_dependencies_with_kind()
+            .filter(|(_, name, _)| name.as_str() == "foo")
+            .map(|(kind, _, version)| (kind, version.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            foo_kinds,
+            vec![
+                (DependencyKind::Dev, "^1.1.0"),
+                (DependencyKind::Regular, "^1.0.0")
+            ]
+        );
+        assert_eq!(package_json.all_dependencies_with_kind().count(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_effective_overrides() -> Result<()> {
+        let package_json = PackageJson::from_value(json!({
+            "resolutions": {
+                "foo": "1.0.0",
+                "bar": "2.0.0",
+                "baz/qux": "2.5.0",
+                "@scope/a/b": "4.0.0",
+                "**/c": "5.0.0"
+            },
+            "overrides": {"foo": "1.1.0", "baz": {"qux": "3.0.0"}}
+        }))?;
+        assert_eq!(
+            package_json.effective_overrides(),
+            [
+                ("**/c", "5.0.0"),
+                ("@scope/a>b", "4.0.0"),
+                ("bar", "2.0.0"),
+                ("baz>qux", "3.0.0"),
+                ("foo", "1.1.0")
+            ]
+            .into_iter()
+            .map(|(name, version)| (name.to_string(), version.to_string()))
+            .collect::<BTreeMap<_, _>>()
+        );
+        assert_eq!(
+            package_json.resolutions.as_ref().unwrap()["foo"],
+            "1.0.0",
+            "raw resolutions should be untouched"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_effective_overrides_resolved() -> Result<()> {
+        let package_json = PackageJson::from_value(json!({
+            "resolutions": {"foo": "1.0.0"},
+            "overrides": {"bar": "workspace:^", "baz": {"qux": "file:../qux"}}
+        }))?;
+        assert_eq!(
+            package_json.effective_overrides_resolved(),
+            vec![
+                ("bar".to_string(), VersionProtocol::Workspace),
+                ("baz>qux".to_string(), VersionProtocol::File),
+                ("foo".to_string(), VersionProtocol::Semver),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_workspace_dependencies() -> Result<()> {
+        let package_json = PackageJson::from_value(json!({
+            "dependencies": {"a": "workspace:*", "b": "^1.0.0"},
+            "devDependencies": {"c": "workspace:^", "d": "1.2.3"},
+            "peerDependencies": {"e": "workspace:~"}
+        }))?;
+        assert_eq!(
+            package_json
+                .workspace_dependencies()
+                .map(|(name, version)| (name.as_str(), version.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("c", "workspace:^"),
+                ("a", "workspace:*"),
+                ("e", "workspace:~")
+            ]
+        );
+        Ok(())
+    }
+
+    #[test_case(Some("foo"), Ok(()) ; "simple name")]
+    #[test_case(Some("@scope/valid"), Ok(()) ; "scoped name")]
+    #[test_case(Some("foo.js-bar_baz"), Ok(()) ; "punctuation")]
+    #[test_case(None, Err(NameError::Missing) ; "missing")]
+    #[test_case(Some(""), Err(NameError::Empty) ; "empty")]
+    #[test_case(Some("UPPERCASE"), Err(NameError::Uppercase) ; "uppercase")]
+    #[test_case(Some(".hidden"), Err(NameError::LeadingPeriod) ; "leading period")]
+    #[test_case(Some("_private"), Err(NameError::LeadingUnderscore) ; "leading underscore")]
+    #[test_case(Some(" foo"), Err(NameError::Whitespace) ; "leading space")]
+    #[test_case(Some("foo/bar"), Err(NameError::InvalidCharacter('/')) ; "unscoped slash")]
+    #[test_case(Some("@scope"), Err(NameError::InvalidScope) ; "scope without name")]
+    #[test_case(Some("@scope/a/b"), Err(NameError::InvalidCharacter('/')) ; "nested scope")]
+    fn test_validate_name(name: Option<&str>, expected: Result<(), NameError>) {
+        let package_json = PackageJson {
+            name: name.map(|name| name.to_string()),
+            ..Default::default()
+        };
+        assert_eq!(package_json.validate_name(), expected);
+    }
+
+    #[test]
+    fn test_validate_name_too_long() {
+        let package_json = PackageJson {
+            name: Some("a".repeat(215)),
+            ..Default::default()
+        };
+        assert_eq!(package_json.validate_name(), Err(NameError::TooLong(215)));
+    }
+
+    #[test_case("workspace:*", VersionProtocol::Workspace ; "workspace star")]
+    #[test_case("workspace:^1.0.0", VersionProtocol::Workspace ; "workspace range")]
+    #[test_case("catalog:", VersionProtocol::Catalog ; "default catalog protocol")]
+    #[test_case("catalog:react17", VersionProtocol::Catalog ; "named catalog protocol")]
+    #[test_case("npm:bar@1", VersionProtocol::NpmAlias ; "npm alias")]
+    #[test_case("npm:@scope/pkg@^1", VersionProtocol::NpmAlias ; "scoped npm alias")]
+    #[test_case("file:../foo", VersionProtocol::File ; "file")]
+    #[test_case("link:../foo", VersionProtocol::Link ; "link")]
+    #[test_case("git://github.com/foo/bar.git", VersionProtocol::Git ; "git")]
+    #[test_case("git+ssh://git@github.com:foo/bar.git#v1", VersionProtocol::Git ; "git ssh")]
+    #[test_case("https://example.com/foo-1.0.0.tgz", VersionProtocol::Url ; "tarball url")]
+    #[test_case("^1.2.3", VersionProtocol::Semver ; "caret range")]
+    #[test_case("1.2.3", VersionProtocol::Semver ; "exact version")]
+    #[test_case(">=1 <2", VersionProtocol::Semver ; "compound range")]
+    fn test_classify_version(spec: &str, expected: VersionProtocol) {
+        assert_eq!(PackageJson::classify_version(spec), expected);
+    }
+
+    #[test_case("npm:bar@1", Some(("bar", Some("1"))) ; "alias")]
+    #[test_case("npm:@scope/bar@^1", Some(("@scope/bar", Some("^1"))) ; "scoped alias")]
+    #[test_case("npm:bar", Some(("bar", None)) ; "alias without range")]
+    #[test_case("^1.2.3", None ; "not an alias")]
+    fn test_resolve_alias(spec: &str, expected: Option<(&str, Option<&str>)>) {
+        assert_eq!(
+            PackageJson::resolve_alias(spec),
+            expected.map(|(name, range)| AliasTarget {
+                name: name.to_string(),
+                range: range.map(|range| range.to_string()),
+            })
+        );
+    }
+
+    #[test_case(None, "react", Some("^18.2.0") ; "default catalog")]
+    #[test_case(Some("default"), "react", Some("^18.2.0") ; "explicit default catalog")]
+    #[test_case(Some("react17"), "react", Some("^17.0.2") ; "named catalog")]
+    #[test_case(Some("react17"), "lodash", None ; "missing from named catalog")]
+    #[test_case(Some("missing"), "react", None ; "missing catalog")]
+    fn test_resolve_catalog(name: Option<&str>, pkg: &str, expected: Option<&str>) {
+        let package_json = PackageJson::from_value(json!({
+            "catalog": {"react": "^18.2.0", "lodash": "^4.17.21"},
+            "catalogs": {"react17": {"react": "^17.0.2"}}
+        }))
+        .unwrap();
+        assert_eq!(package_json.resolve_catalog(name, pkg), expected);
+    }
+
+    #[test]
+    fn test_pnpm_peer_dependency_rules() -> Result<()> {
+        let package_json = PackageJson::from_value(json!({
+            "pnpm": {"peerDependencyRules": {"ignoreMissing": ["@types/*"]}}
+        }))?;
+        let rules = package_json
+            .pnpm
+            .and_then(|pnpm| pnpm.peer_dependency_rules)
+            .unwrap();
+        assert_eq!(rules.ignore_missing, Some(vec!["@types/*".to_string()]));
+        assert_eq!(rules.allowed_versions, None);
+        Ok(())
+    }
+
+    #[test_case("foo", ("foo", None) ; "bare name")]
+    #[test_case("foo@^1.0.0", ("foo", Some("^1.0.0")) ; "name with range")]
+    #[test_case("@scope/foo", ("@scope/foo", None) ; "scoped name")]
+    #[test_case("@scope/foo@<2", ("@scope/foo", Some("<2")) ; "scoped name with range")]
+    fn test_parsed_overrides(selector: &str, expected: (&str, Option<&str>)) {
+        let pnpm: PnpmConfig =
+            serde_json::from_value(json!({"overrides": {selector: "1.2.3"}})).unwrap();
+        let (name, range) = expected;
+        assert_eq!(
+            pnpm.parsed_overrides(),
+            vec![OverrideEntry {
+                name: name.to_string(),
+                range: range.map(|range| range.to_string()),
+                version: "1.2.3".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_diff() -> Result<()> {
+        let before = PackageJson::from_value(json!({
+            "name": "foo",
+            "version": "1.0.0",
+            "dependencies": {"react": "^18.2.0", "lodash": "^4.17.21"},
+            "scripts": {"build": "tsc", "lint": "eslint ."}
+        }))?;
+        let after = PackageJson::from_value(json!({
+            "name": "foo",
+            "version": "1.1.0",
+            "dependencies": {"react": "^18.3.0", "lodash": "^4.17.21"},
+            "devDependencies": {"typescript": "^5.0.0"},
+            "scripts": {"build": "tsc"}
+        }))?;
+
+        let diff = before.diff(&after);
+        assert_eq!(
+            diff.dependencies[&DependencyKind::Regular].changed["react"],
+            Change {
+                before: "^18.2.0".to_string(),
+                after: "^18.3.0".to_string(),
+            }
+        );
+        assert_eq!(
+            diff.dependencies[&DependencyKind::Dev].added,
+            BTreeMap::from([("typescript".to_string(), "^5.0.0".to_string())])
+        );
+        assert_eq!(
+            diff.scripts.removed,
+            BTreeMap::from([("lint".to_string(), "eslint .".to_string())])
+        );
+        assert!(diff.scripts.added.is_empty() && diff.scripts.changed.is_empty());
+        assert_eq!(
+            diff.fields,
+            BTreeMap::from([(
+                "version".to_string(),
+                Change {
+                    before: Some("1.0.0".to_string()),
+                    after: Some("1.1.0".to_string()),
+                }
+            )])
+        );
+        assert_eq!(
+            serde_json::to_value(&diff)?["dependencies"]["dev"]["added"],
+            json!({"typescript": "^5.0.0"})
+        );
+
+        assert_eq!(before.diff(&before), PackageJsonDiff::default());
+        Ok(())
+    }
+
+    #[test_case("pnpm@8.15.0", Some(("pnpm", "8.15.0", None)) ; "no hash")]
+    #[test_case("yarn@4.1.0+sha224.abc123", Some(("yarn", "4.1.0", Some(("sha224", "abc123")))) ; "with hash")]
+    #[test_case("pnpm", None ; "missing version")]
+    #[test_case("left-pad@1.0.0", None ; "unknown package manager")]
+    #[test_case("pnpm@8.0.0+build.1", Some(("pnpm", "8.0.0+build.1", None)) ; "build metadata")]
+    #[test_case("pnpm@8.15.0+sha224", Some(("pnpm", "8.15.0+sha224", None)) ; "hash without digest")]
+    #[test_case("pnpm@9.0.0-rc.1+sha256.abc123", Some(("pnpm", "9.0.0-rc.1", Some(("sha256", "abc123")))) ; "prerelease with hash")]
+    fn test_package_manager_spec(spec: &str, expected: Option<(&str, &str, Option<(&str, &str)>)>) {
+        let expected = expected.map(|(name, version, hash)| PackageManagerSpec {
+            name: name.to_string(),
+            version: version.to_string(),
+            hash: hash.map(|(algorithm, digest)| PackageManagerHash {
+                algorithm: algorithm.to_string(),
+                digest: digest.to_string(),
+            }),
+        });
+        assert_eq!(PackageManagerSpec::parse(spec), expected);
+    }
+
+    #[test_case("sha224.ea09ae9cc6768c50fcee903ed054556e5bfc8347907f12598aa24193", true ; "sha224")]
+    #[test_case("sha256.2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824", true ; "sha256")]
+    #[test_case("sha512.9b71d224bd62f3785d96d46ad3ea3d73319bfbc2890caadae2dff72519673ca72323c3d99ba5c11d7c7acc6e14b8c5da0c4663475c2e5c3adef46f73bcdec043", true ; "sha512")]
+    #[test_case("sha256.0000000000000000000000000000000000000000000000000000000000000000", false ; "mismatch")]
+    fn test_package_manager_verify_against(hash: &str, matches: bool) {
+        let spec = PackageManagerSpec::parse(&format!("pnpm@8.15.0+{hash}")).unwrap();
+        let result = spec.verify_against(b"hello");
+        assert_eq!(result.is_ok(), matches, "{result:?}");
+    }
+
+    #[test]
+    fn test_package_manager_verify_unsupported_algorithm() {
+        let spec = PackageManagerSpec::parse("pnpm@8.15.0+sha1.abc").unwrap();
+        assert_eq!(
+            spec.verify_against(b"hello"),
+            Err(IntegrityError::UnsupportedAlgorithm("sha1".to_string()))
+        );
+        let spec = PackageManagerSpec::parse("pnpm@8.15.0").unwrap();
+        assert_eq!(spec.verify_against(b"hello"), Ok(()));
+    }
+
+    #[test_case("foo", ResolutionSelector::Package("foo".to_string()) ; "plain")]
+    #[test_case("@a/b", ResolutionSelector::Package("@a/b".to_string()) ; "scoped")]
+    #[test_case("parent/@a/b", ResolutionSelector::Nested(vec!["parent".to_string(), "@a/b".to_string()]) ; "nested")]
+    #[test_case("**/foo", ResolutionSelector::Unsupported { selector: "**/foo".to_string(), reason: "glob patterns in resolutions are not supported".to_string() } ; "glob")]
+    #[test_case("@a", ResolutionSelector::Unsupported { selector: "@a".to_string(), reason: "scoped package name is missing its name".to_string() } ; "incomplete scope")]
+    fn test_parsed_resolutions(selector: &str, expected: ResolutionSelector) {
+        let package_json =
+            PackageJson::from_value(json!({"resolutions": {selector: "1.0.0"}})).unwrap();
+        assert_eq!(
+            package_json.parsed_resolutions(),
+            vec![ResolutionRule {
+                selector: expected,
+                version: "1.0.0".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_builder() -> Result<()> {
+        let package_json = PackageJson::builder()
+            .name("foo")
+            .version("1.0.0")
+            .add_dependency(DependencyKind::Regular, "react", "^18.2.0")
+            .add_dependency(DependencyKind::Dev, "typescript", "^5.0.0")
+            .add_dependency(DependencyKind::Regular, "lodash", "^4.17.21")
+            .script("build", "tsc")
+            .build();
+
+        assert_eq!(
+            package_json,
+            PackageJson::from_value(json!({
+                "name": "foo",
+                "version": "1.0.0",
+                "dependencies": {"react": "^18.2.0", "lodash": "^4.17.21"},
+                "devDependencies": {"typescript": "^5.0.0"},
+                "scripts": {"build": "tsc"}
+            }))?
+        );
+        assert_eq!(
+            serde_json::to_value(&package_json)?,
+            json!({
+                "name": "foo",
+                "version": "1.0.0",
+                "dependencies": {"lodash": "^4.17.21", "react": "^18.2.0"},
+                "devDependencies": {"typescript": "^5.0.0"},
+                "scripts": {"build": "tsc"}
+            })
+        );
+        assert_eq!(PackageJson::builder().build(), PackageJson::default());
+        Ok(())
+    }
+
+    #[test]
+    fn test_content_hash_order_independent() -> Result<()> {
+        let a = PackageJson::from_str(
+            r#"{"name": "foo", "version": "1.0.0", "extra": {"b": 1, "a": [{"y": 1, "x": 2}]}}"#,
+        )?;
+        let b = PackageJson::from_str(
+            r#"{
+                "extra": {"a": [{"x": 2, "y": 1}], "b": 1},
+                "version": "1.0.0",
+                "name": "foo"
+            }"#,
+        )?;
+        assert_eq!(a.content_hash(), b.content_hash());
+
+        let c = PackageJson::from_str(r#"{"name": "foo", "version": "1.0.1"}"#)?;
+        assert_ne!(a.content_hash(), c.content_hash());
 
         Ok(())
     }
@@ -24,7 +24,7 @@ use which::which;
 
 use crate::{
     discovery,
-    package_json::PackageJson,
+    package_json::{PackageJson, Workspaces},
     package_manager::{bun::BunDetector, npm::NpmDetector, pnpm::PnpmDetector, yarn::YarnDetector},
 };
 
@@ -38,31 +38,6 @@ struct PackageJsonWorkspaces {
     workspaces: Workspaces,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
-#[serde(untagged)]
-enum Workspaces {
-    TopLevel(Vec<String>),
-    Nested { packages: Vec<String> },
-}
-
-impl AsRef<[String]> for Workspaces {
-    fn as_ref(&self) -> &[String] {
-        match self {
-            Workspaces::TopLevel(packages) => packages.as_slice(),
-            Workspaces::Nested { packages } => packages.as_slice(),
-        }
-    }
-}
-
-impl From<Workspaces> for Vec<String> {
-    fn from(value: Workspaces) -> Self {
-        match value {
-            Workspaces::TopLevel(packages) => packages,
-            Workspaces::Nested { packages } => packages,
-        }
-    }
-}
-
 #[derive(Debug, Serialize, PartialEq, Eq, Clone, Copy)]
 #[serde(rename_all = "lowercase")]
 pub enum PackageManager {